@@ -1,6 +1,13 @@
-use crate::{Member, Type};
+use crate::{DecimalLiteral, IntegerLiteral, Member, Span, StringLiteral, Type};
 
-#[derive(Debug, Clone)]
+/// With the `serde` feature, definitions are serialized as `{"kind": "interface", "value": {..}}`,
+/// where `kind` is the variant in snake case.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum Definition {
     Interface(Interface),
     InterfaceMixin(InterfaceMixin),
@@ -13,108 +20,141 @@ pub enum Definition {
     Typedef(Typedef),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Interface {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub partial: bool,
     pub identifier: String,
     pub inheritance: Option<String>,
     pub members: Vec<Member>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceMixin {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub partial: bool,
     pub identifier: String,
     pub members: Vec<Member>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Includes {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub interface: String,
     pub mixin: String,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CallbackInterface {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub identifier: String,
     pub members: Vec<Member>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Namespace {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub partial: bool,
     pub identifier: String,
     pub members: Vec<Member>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dictionary {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub partial: bool,
     pub identifier: String,
     pub inheritance: Option<String>,
     pub members: Vec<DictionaryMember>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Enumeration {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub identifier: String,
-    pub values: Vec<String>,
+    pub values: Vec<StringLiteral>,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CallbackFunction {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub identifier: String,
     pub r#type: Type,
     pub arguments: Vec<Argument>,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Typedef {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub r#type: Type,
     pub identifier: String,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DictionaryMember {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub required: bool,
     pub r#type: Type,
     pub identifier: String,
     pub default: Option<DefaultValue>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExtendedAttribute {
     pub identifier: String,
     pub value: Option<ExtAttrValue>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+/// With the `serde` feature, values are serialized as `{"kind": "argument_list", "value": [..]}`,
+/// where `kind` is the variant in snake case. `value` is left out for [`ExtAttrValue::Wildcard`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum ExtAttrValue {
     ArgumentList(Vec<Argument>),
     NamedArgumentList(NamedArgumentList),
     Identifier(String),
     IdentifierList(Vec<String>),
+    String(StringLiteral),
+    StringList(Vec<StringLiteral>),
 
     Wildcard,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NamedArgumentList {
     pub identifier: String,
     pub arguments: Vec<Argument>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Argument {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub optional: bool,
@@ -122,14 +162,16 @@ pub struct Argument {
     pub variadic: bool,
     pub identifier: String,
     pub default: Option<DefaultValue>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DefaultValue {
     Boolean(bool),
-    Integer(i64),
-    Decimal(f64),
-    String(String),
+    Integer(IntegerLiteral),
+    Decimal(DecimalLiteral),
+    String(StringLiteral),
 
     Null,
     Infinity,
@@ -194,9 +236,61 @@ impl Definition {
             _ => false,
         }
     }
+
+    /// Identifier of the interface or dictionary this one inherits from, if any.
+    pub fn get_inheritance(&self) -> Option<&str> {
+        match self {
+            Definition::Interface(interface) => interface.inheritance.as_deref(),
+            Definition::Dictionary(dictionary) => dictionary.inheritance.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn get_span(&self) -> Option<&Span> {
+        match self {
+            Definition::Interface(interface) => interface.span.as_ref(),
+            Definition::InterfaceMixin(interface_mixin) => interface_mixin.span.as_ref(),
+            Definition::Includes(includes) => includes.span.as_ref(),
+            Definition::CallbackInterface(cb_interface) => cb_interface.span.as_ref(),
+            Definition::Namespace(namespace) => namespace.span.as_ref(),
+            Definition::Dictionary(dictionary) => dictionary.span.as_ref(),
+            Definition::Enumeration(r#enum) => r#enum.span.as_ref(),
+            Definition::CallbackFunction(cb_function) => cb_function.span.as_ref(),
+            Definition::Typedef(typedef) => typedef.span.as_ref(),
+        }
+    }
+
+    pub fn get_span_mut(&mut self) -> &mut Option<Span> {
+        match self {
+            Definition::Interface(interface) => &mut interface.span,
+            Definition::InterfaceMixin(interface_mixin) => &mut interface_mixin.span,
+            Definition::Includes(includes) => &mut includes.span,
+            Definition::CallbackInterface(cb_interface) => &mut cb_interface.span,
+            Definition::Namespace(namespace) => &mut namespace.span,
+            Definition::Dictionary(dictionary) => &mut dictionary.span,
+            Definition::Enumeration(r#enum) => &mut r#enum.span,
+            Definition::CallbackFunction(cb_function) => &mut cb_function.span,
+            Definition::Typedef(typedef) => &mut typedef.span,
+        }
+    }
 }
 
 /* Trait implementations */
 
+impl_eq_without_span! {
+    Interface { ext_attrs, partial, identifier, inheritance, members }
+    InterfaceMixin { ext_attrs, partial, identifier, members }
+    Includes { ext_attrs, interface, mixin }
+    CallbackInterface { ext_attrs, identifier, members }
+    Namespace { ext_attrs, partial, identifier, members }
+    Dictionary { ext_attrs, partial, identifier, inheritance, members }
+    Enumeration { ext_attrs, identifier, values }
+    CallbackFunction { ext_attrs, identifier, r#type, arguments }
+    Typedef { ext_attrs, r#type, identifier }
+    DictionaryMember { ext_attrs, required, r#type, identifier, default }
+    ExtendedAttribute { identifier, value }
+    Argument { ext_attrs, optional, r#type, variadic, identifier, default }
+}
+
 // ...
 // ...
@@ -1,24 +1,13 @@
 use std::fmt;
 
-use itertools::{join, Itertools};
+use itertools::join;
 
 use crate::{
-    parser, Argument, CallbackFunction, CallbackInterface, DefaultValue, Definition, Dictionary,
+    Argument, CallbackFunction, CallbackInterface, DefaultValue, Definition, Dictionary,
     DictionaryMember, Enumeration, ExtAttrValue, ExtendedAttribute, Includes, Interface,
     InterfaceMixin, NamedArgumentList, Namespace, Typedef,
 };
 
-// TODO: Find a better solution to determine if an identifier has to be in quotes.
-fn display_ext_attr_identifier(identifier: &str) -> String {
-    if let Ok((rest, identifier)) = parser::parse_identifier(identifier) {
-        if rest.is_empty() {
-            return identifier;
-        }
-    }
-
-    format!("{:?}", identifier)
-}
-
 impl fmt::Display for Definition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -151,7 +140,7 @@ impl fmt::Display for Enumeration {
 
         write!(f, "enum {} {{", self.identifier)?;
         if !self.values.is_empty() {
-            write!(f, "\n\t\"{}\"", join(&self.values, "\",\n\t\""))?;
+            write!(f, "\n\t{}", join(&self.values, ",\n\t"))?;
         }
         write!(f, "\n}};")
     }
@@ -222,19 +211,12 @@ impl fmt::Display for ExtAttrValue {
                 write!(f, "({})", join(arguments, ", "))
             }
             ExtAttrValue::NamedArgumentList(named_args_list) => write!(f, "={}", named_args_list),
-            ExtAttrValue::Identifier(identifier) => {
-                write!(f, "={}", display_ext_attr_identifier(identifier))
-            }
+            ExtAttrValue::Identifier(identifier) => write!(f, "={}", identifier),
             ExtAttrValue::IdentifierList(identifier_list) => {
-                write!(
-                    f,
-                    "=({})",
-                    identifier_list
-                        .iter()
-                        .map(|identifier| display_ext_attr_identifier(identifier))
-                        .join(", ")
-                )
+                write!(f, "=({})", join(identifier_list, ", "))
             }
+            ExtAttrValue::String(string) => write!(f, "={}", string),
+            ExtAttrValue::StringList(string_list) => write!(f, "=({})", join(string_list, ", ")),
             ExtAttrValue::Wildcard => write!(f, "=*"),
         }
     }
@@ -280,7 +262,7 @@ impl fmt::Display for DefaultValue {
             }
             DefaultValue::Integer(integer) => write!(f, "{}", integer),
             DefaultValue::Decimal(decimal) => write!(f, "{}", decimal),
-            DefaultValue::String(string) => write!(f, "{:?}", string),
+            DefaultValue::String(string) => write!(f, "{}", string),
             DefaultValue::Null => write!(f, "null"),
             DefaultValue::Infinity => write!(f, "Infinity"),
             DefaultValue::NegativeInfinity => write!(f, "-Infinity"),
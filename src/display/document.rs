@@ -0,0 +1,83 @@
+use std::fmt;
+
+use crate::{
+    document::{body_members, header, Node},
+    Document,
+};
+
+impl Document {
+    // Trivia in front of a node, or `separator` if it doesn't have any that can be reused.
+    fn write_leading(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        node: Option<&Node>,
+        first: bool,
+        separator: &str,
+    ) -> fmt::Result {
+        match node.map(|node| &self.source[node.leading.clone()]) {
+            // Without a line break, the node would end up on the same line as the previous one,
+            // since it might have been moved.
+            Some(leading) if first || leading.contains('\n') => write!(f, "{}", leading),
+            _ if first => Ok(()),
+            _ => write!(f, "{}", separator),
+        }
+    }
+
+    fn write_node(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        node: Option<&Node>,
+        printed: &dyn fmt::Display,
+    ) -> fmt::Result {
+        match node {
+            Some(node) if node.canonical == printed.to_string() => {
+                write!(f, "{}", &self.source[node.leading.end..node.trailing.end])
+            }
+            // Comments after a modified node most likely still belong to it.
+            Some(node) => write!(f, "{}{}", printed, &self.source[node.trailing.clone()]),
+            None => write!(f, "{}", printed),
+        }
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, definition) in self.definitions.iter().enumerate() {
+            let node = definition.get_span().and_then(|span| self.node(span));
+            self.write_leading(f, node, i == 0, "\n\n")?;
+
+            // If a definition with a body was modified, its members are printed one by one, so that
+            // the comments of the unmodified ones are kept.
+            let body = node.and_then(|node| Some((node, node.body.as_ref()?)));
+            let (Some((node, body)), Some(members)) = (body, body_members(definition)) else {
+                self.write_node(f, node, definition)?;
+                continue;
+            };
+            if node.canonical == definition.to_string() {
+                self.write_node(f, Some(node), definition)?;
+                continue;
+            }
+
+            let header = header(definition);
+            if header == body.canonical_header {
+                write!(f, "{}", &self.source[body.header.clone()])?;
+            } else {
+                write!(f, "{}", header)?;
+            }
+            for (span, member) in &members {
+                let node = span.and_then(|span| self.node(span));
+                self.write_leading(f, node, false, "\n\t")?;
+                self.write_node(f, node, *member)?;
+            }
+            let trailing = &self.source[body.trailing.clone()];
+            if trailing.contains('\n') {
+                write!(f, "{}}};", trailing)?;
+            } else {
+                write!(f, "\n}};")?;
+            }
+            write!(f, "{}", &self.source[node.trailing.clone()])?;
+        }
+
+        write!(f, "{}", &self.source[self.trailing.clone()])
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+use crate::{parser, DecimalLiteral, IntegerLiteral, StringLiteral};
+
+impl fmt::Display for IntegerLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.raw {
+            Some(raw) if parser::integer_value(raw) == Some(self.value) => write!(f, "{}", raw),
+            _ => write!(f, "{}", self.value),
+        }
+    }
+}
+
+impl fmt::Display for DecimalLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.raw {
+            Some(raw) if parser::decimal_value(raw) == Some(self.value) => write!(f, "{}", raw),
+            // Decimals are only used in constant and default values, which have tokens of their
+            // own for these.
+            _ if self.value.is_nan() => write!(f, "NaN"),
+            _ if self.value == f64::INFINITY => write!(f, "Infinity"),
+            _ if self.value == f64::NEG_INFINITY => write!(f, "-Infinity"),
+            // Whole numbers get a decimal point, so that they aren't read back as integer.
+            _ if self.value.fract() == 0.0 => write!(f, "{}.0", self.value),
+            _ => write!(f, "{}", self.value),
+        }
+    }
+}
+
+impl fmt::Display for StringLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.as_str())
+    }
+}
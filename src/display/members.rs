@@ -146,7 +146,13 @@ impl fmt::Display for Iterable {
             write!(f, "{}, ", key_type)?;
         }
 
-        write!(f, "{}>;", self.value_type)
+        write!(f, "{}>", self.value_type)?;
+
+        if let Some(arguments) = &self.arguments {
+            write!(f, "({})", join(arguments, ", "))?;
+        }
+
+        write!(f, ";")
     }
 }
 
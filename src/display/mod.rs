@@ -1,3 +1,8 @@
 mod definitions;
+mod document;
+mod literals;
 mod members;
+mod printer;
 mod types;
+
+pub use printer::{FormatOptions, IndentStyle, Printer};
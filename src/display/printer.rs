@@ -0,0 +1,246 @@
+use itertools::join;
+
+use crate::{document, Argument, Definition, ExtendedAttribute, Member};
+
+/// How the members of a definition are indented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentStyle {
+    /// One tab per level. A tab counts as 4 columns towards [`FormatOptions::max_width`].
+    Tabs,
+    /// The given number of spaces per level.
+    Spaces(usize),
+}
+
+/// Options of a [`Printer`]. The default options print the definitions like
+/// [`to_string`](crate::to_string) does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub indent: IndentStyle,
+    /// Lines longer than this are wrapped, if possible. Argument lists are broken up into one
+    /// argument per line first. If that isn't enough, extended attributes are moved onto their own
+    /// line, and broken up into one extended attribute per line if that line is still too long.
+    /// `None` never wraps lines.
+    pub max_width: Option<usize>,
+    /// Number of empty lines between definitions.
+    pub blank_lines: usize,
+    /// Whether the last value of an enumeration is followed by a comma, as in `"b",\n};`.
+    pub trailing_comma: bool,
+}
+
+/// Prints definitions as Web IDL, formatted according to [`FormatOptions`].
+#[derive(Debug, Clone, Default)]
+pub struct Printer {
+    options: FormatOptions,
+}
+
+// The lines of a definition, as they are printed.
+struct Lines<'a> {
+    options: &'a FormatOptions,
+    lines: Vec<String>,
+}
+
+/* Functionality implementations */
+
+impl Printer {
+    pub fn new(options: FormatOptions) -> Self {
+        Printer { options }
+    }
+
+    pub fn options(&self) -> &FormatOptions {
+        &self.options
+    }
+
+    pub fn print(&self, definitions: &[Definition]) -> String {
+        let separator = "\n".repeat(self.options.blank_lines + 1);
+        join(
+            definitions
+                .iter()
+                .map(|definition| self.print_definition(definition)),
+            &separator,
+        )
+    }
+
+    pub fn print_definition(&self, definition: &Definition) -> String {
+        let mut lines = Lines {
+            options: &self.options,
+            lines: vec![],
+        };
+        lines.ext_attrs(0, definition.get_ext_attrs());
+
+        let mut bare = definition.clone();
+        bare.get_ext_attrs_mut().clear();
+        match definition {
+            Definition::Interface(interface) => lines.body(&bare, &interface.members),
+            Definition::InterfaceMixin(interface_mixin) => {
+                lines.body(&bare, &interface_mixin.members)
+            }
+            Definition::CallbackInterface(cb_interface) => lines.body(&bare, &cb_interface.members),
+            Definition::Namespace(namespace) => lines.body(&bare, &namespace.members),
+            Definition::Dictionary(dictionary) => {
+                lines.line(0, document::header(&bare));
+                for member in &dictionary.members {
+                    let mut bare = member.clone();
+                    bare.ext_attrs.clear();
+                    lines.item(1, &member.ext_attrs, bare.to_string(), None);
+                }
+                lines.line(0, "};".to_string());
+            }
+            Definition::Enumeration(enumeration) => {
+                lines.line(0, format!("enum {} {{", enumeration.identifier));
+                for (i, value) in enumeration.values.iter().enumerate() {
+                    let comma = i + 1 < enumeration.values.len() || self.options.trailing_comma;
+                    lines.line(1, format!("{}{}", value, if comma { "," } else { "" }));
+                }
+                lines.line(0, "};".to_string());
+            }
+            Definition::CallbackFunction(cb_function) => {
+                let mut head = cb_function.clone();
+                head.ext_attrs.clear();
+                head.arguments.clear();
+                let callable = (callable_head(head.to_string()), &cb_function.arguments[..]);
+                lines.item(0, &[], bare.to_string(), Some(callable));
+            }
+            Definition::Includes(_) | Definition::Typedef(_) => lines.line(0, bare.to_string()),
+        }
+
+        lines.lines.join("\n")
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            indent: IndentStyle::Tabs,
+            max_width: None,
+            blank_lines: 1,
+            trailing_comma: false,
+        }
+    }
+}
+
+impl IndentStyle {
+    fn indent(&self, depth: usize) -> String {
+        match self {
+            IndentStyle::Tabs => "\t".repeat(depth),
+            IndentStyle::Spaces(spaces) => " ".repeat(spaces * depth),
+        }
+    }
+
+    fn width(&self, depth: usize) -> usize {
+        match self {
+            IndentStyle::Tabs => 4 * depth,
+            IndentStyle::Spaces(spaces) => spaces * depth,
+        }
+    }
+}
+
+impl<'a> Lines<'a> {
+    fn line(&mut self, depth: usize, text: String) {
+        self.lines
+            .push(format!("{}{}", self.options.indent.indent(depth), text));
+    }
+
+    fn fits(&self, depth: usize, text: &str) -> bool {
+        match self.options.max_width {
+            Some(max_width) => self.options.indent.width(depth) + text.chars().count() <= max_width,
+            None => true,
+        }
+    }
+
+    // A definition with members, such as an interface. `bare` has no extended attributes.
+    fn body(&mut self, bare: &Definition, members: &[Member]) {
+        self.line(0, document::header(bare));
+        for member in members {
+            let mut bare = member.clone();
+            bare.get_ext_attrs_mut().clear();
+            let text = bare.to_string();
+            let arguments = match member {
+                Member::Operation(operation) => Some(&operation.arguments[..]),
+                Member::Constructor(constructor) => Some(&constructor.arguments[..]),
+                Member::Iterable(iterable) => iterable.arguments.as_deref(),
+                _ => None,
+            };
+            let callable = arguments.map(|arguments| {
+                match &mut bare {
+                    Member::Operation(operation) => operation.arguments.clear(),
+                    Member::Constructor(constructor) => constructor.arguments.clear(),
+                    Member::Iterable(iterable) => iterable.arguments = Some(vec![]),
+                    _ => {}
+                }
+                (callable_head(bare.to_string()), arguments)
+            });
+            self.item(1, member.get_ext_attrs(), text, callable);
+        }
+        self.line(0, "};".to_string());
+    }
+
+    // A member or callback function, printed as `bare` with `ext_attrs` in front of it. If it
+    // takes arguments, `callable` is the text up to and including `(`, and the arguments.
+    fn item(
+        &mut self,
+        depth: usize,
+        ext_attrs: &[ExtendedAttribute],
+        bare: String,
+        callable: Option<(String, &[Argument])>,
+    ) {
+        let prefix = match ext_attrs {
+            [] => String::new(),
+            ext_attrs => format!("[{}] ", join(ext_attrs, ", ")),
+        };
+        if self.fits(depth, &format!("{}{}", prefix, bare)) {
+            return self.line(depth, format!("{}{}", prefix, bare));
+        }
+
+        let callable = callable.filter(|(_, arguments)| !arguments.is_empty());
+        if let Some((head, arguments)) = &callable {
+            if self.fits(depth, &format!("{}{}", prefix, head)) {
+                return self.arguments(depth, format!("{}{}", prefix, head), arguments);
+            }
+        }
+
+        self.ext_attrs(depth, ext_attrs);
+        match callable {
+            Some((head, arguments)) if !self.fits(depth, &bare) => {
+                self.arguments(depth, head, arguments)
+            }
+            // Can't be wrapped any further.
+            _ => self.line(depth, bare),
+        }
+    }
+
+    fn arguments(&mut self, depth: usize, head: String, arguments: &[Argument]) {
+        self.line(depth, head);
+        for (i, argument) in arguments.iter().enumerate() {
+            let comma = if i + 1 < arguments.len() { "," } else { "" };
+            self.line(depth + 1, format!("{}{}", argument, comma));
+        }
+        self.line(depth, ");".to_string());
+    }
+
+    // Extended attributes on a line of their own.
+    fn ext_attrs(&mut self, depth: usize, ext_attrs: &[ExtendedAttribute]) {
+        if ext_attrs.is_empty() {
+            return;
+        }
+
+        let flat = format!("[{}]", join(ext_attrs, ", "));
+        if self.fits(depth, &flat) {
+            return self.line(depth, flat);
+        }
+
+        self.line(depth, "[".to_string());
+        for (i, ext_attr) in ext_attrs.iter().enumerate() {
+            let comma = if i + 1 < ext_attrs.len() { "," } else { "" };
+            self.line(depth + 1, format!("{}{}", ext_attr, comma));
+        }
+        self.line(depth, "]".to_string());
+    }
+}
+
+// The text of a callable up to and including `(`, given the text of it without arguments.
+fn callable_head(printed: String) -> String {
+    match printed.strip_suffix(");") {
+        Some(head) => head.to_string(),
+        None => printed,
+    }
+}
@@ -153,6 +153,8 @@ impl fmt::Display for PrimitiveType {
             PrimitiveType::Object => write!(f, "object"),
             PrimitiveType::Symbol => write!(f, "symbol"),
             PrimitiveType::ArrayBuffer => write!(f, "ArrayBuffer"),
+            PrimitiveType::SharedArrayBuffer => write!(f, "SharedArrayBuffer"),
+            PrimitiveType::DataView => write!(f, "DataView"),
             PrimitiveType::Int8Array => write!(f, "Int8Array"),
             PrimitiveType::Int16Array => write!(f, "Int16Array"),
             PrimitiveType::Int32Array => write!(f, "Int32Array"),
@@ -162,6 +164,7 @@ impl fmt::Display for PrimitiveType {
             PrimitiveType::Uint8ClampedArray => write!(f, "Uint8ClampedArray"),
             PrimitiveType::BigInt64Array => write!(f, "BigInt64Array"),
             PrimitiveType::BigUint64Array => write!(f, "BigUint64Array"),
+            PrimitiveType::Float16Array => write!(f, "Float16Array"),
             PrimitiveType::Float32Array => write!(f, "Float32Array"),
             PrimitiveType::Float64Array => write!(f, "Float64Array"),
         }
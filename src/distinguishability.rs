@@ -0,0 +1,191 @@
+use std::mem;
+
+use crate::{Definition, PrimitiveType, StandardTypeName, SymbolKind, SymbolTable, Type};
+
+// Categories of the table in: https://webidl.spec.whatwg.org/#dfn-distinguishable
+// The table's async sequence category is left out, as there is no async sequence type yet.
+pub(crate) enum Category<'a> {
+    Undefined,
+    Boolean,
+    Numeric,
+    Bigint,
+    String,
+    Object,
+    Symbol,
+    /// Interface types and buffer source types.
+    InterfaceLike(&'a StandardTypeName),
+    /// Whether the callback function has `[LegacyTreatNonObjectAsNull]`.
+    CallbackFunction(bool),
+    /// Dictionary types, record types and callback interface types.
+    DictionaryLike,
+    SequenceLike,
+}
+
+/* Functionality implementations */
+
+impl Type {
+    /// Whether a JavaScript value can always be told apart as being of either of the two types,
+    /// as defined in: <https://webidl.spec.whatwg.org/#dfn-distinguishable>
+    ///
+    /// Identifiers are looked up in `symbols`, and typedefs are expanded first. Types that aren't
+    /// defined, `any` and promise types aren't distinguishable from anything. The table's async
+    /// sequence category isn't covered, as [`Type`] has no async sequence type yet.
+    pub fn is_distinguishable_from(&self, other: &Type, symbols: &SymbolTable) -> bool {
+        let expand = |r#type: &Type| r#type.expand(symbols).unwrap_or_else(|_| r#type.clone());
+        distinguishable(&expand(self), &expand(other), symbols)
+    }
+}
+
+// Both types have their typedefs expanded already.
+fn distinguishable(a: &Type, b: &Type, symbols: &SymbolTable) -> bool {
+    let nullable_conflict = |a: &Type, b: &Type| {
+        includes_nullable(a, symbols)
+            && (includes_nullable(b, symbols) || has_dictionary(b, symbols))
+    };
+    if nullable_conflict(a, b) || nullable_conflict(b, a) {
+        return false;
+    }
+
+    match (a, b) {
+        (Type::Union(a), Type::Union(b)) => {
+            let b = b.flattened_member_types_with(symbols);
+            a.flattened_member_types_with(symbols)
+                .iter()
+                .all(|a| b.iter().all(|b| distinguishable(a, b, symbols)))
+        }
+        (Type::Union(union), other) | (other, Type::Union(union)) => union
+            .flattened_member_types_with(symbols)
+            .iter()
+            .all(|r#type| distinguishable(r#type, other, symbols)),
+        _ => match (Category::of(a, symbols), Category::of(b, symbols)) {
+            (Some(a), Some(b)) => a.is_distinguishable_from(&b, symbols),
+            _ => false,
+        },
+    }
+}
+
+// https://webidl.spec.whatwg.org/#dfn-includes-a-nullable-type
+pub(crate) fn includes_nullable(r#type: &Type, symbols: &SymbolTable) -> bool {
+    match r#type {
+        Type::Union(union) => union.nullable || union.nullable_member_types_with(symbols) == 1,
+        r#type => r#type.is_nullable(),
+    }
+}
+
+// Whether the type is a dictionary type, or a union with one among its flattened member types.
+pub(crate) fn has_dictionary(r#type: &Type, symbols: &SymbolTable) -> bool {
+    let is_dictionary = |r#type: &Type| {
+        symbols
+            .resolve(r#type)
+            .is_some_and(|symbol| symbol.kind == SymbolKind::Dictionary)
+    };
+
+    match r#type {
+        Type::Union(union) => union
+            .flattened_member_types_with(symbols)
+            .iter()
+            .any(is_dictionary),
+        r#type => is_dictionary(r#type),
+    }
+}
+
+impl<'a> Category<'a> {
+    pub(crate) fn of(r#type: &'a Type, symbols: &SymbolTable) -> Option<Self> {
+        let standard = match r#type {
+            Type::Sequence(_) | Type::FrozenArray(_) | Type::ObservableArray(_) => {
+                return Some(Category::SequenceLike)
+            }
+            Type::Record(_) => return Some(Category::DictionaryLike),
+            Type::Promise(_) | Type::Union(_) => return None,
+            Type::Standard(standard) => standard,
+        };
+
+        let primitive = match &standard.name {
+            StandardTypeName::Primitive(primitive) => primitive,
+            StandardTypeName::Identifier(identifier) => {
+                let symbol = symbols.get(identifier)?;
+                return match symbol.kind {
+                    SymbolKind::Interface => Some(Category::InterfaceLike(&standard.name)),
+                    SymbolKind::Enumeration => Some(Category::String),
+                    SymbolKind::CallbackFunction => {
+                        let legacy = match symbol.definition {
+                            Some(Definition::CallbackFunction(cb_function)) => {
+                                cb_function.ext_attrs.iter().any(|ext_attr| {
+                                    ext_attr.identifier == "LegacyTreatNonObjectAsNull"
+                                })
+                            }
+                            _ => false,
+                        };
+                        Some(Category::CallbackFunction(legacy))
+                    }
+                    SymbolKind::Dictionary | SymbolKind::CallbackInterface => {
+                        Some(Category::DictionaryLike)
+                    }
+                    _ => None,
+                };
+            }
+        };
+
+        match primitive {
+            PrimitiveType::Any => None,
+            PrimitiveType::Undefined => Some(Category::Undefined),
+            PrimitiveType::Boolean => Some(Category::Boolean),
+            PrimitiveType::Byte
+            | PrimitiveType::Octet
+            | PrimitiveType::Short
+            | PrimitiveType::UnsignedShort
+            | PrimitiveType::Long
+            | PrimitiveType::UnsignedLong
+            | PrimitiveType::LongLong
+            | PrimitiveType::UnsignedLongLong
+            | PrimitiveType::Float
+            | PrimitiveType::UnrestrictedFloat
+            | PrimitiveType::Double
+            | PrimitiveType::UnrestrictedDouble => Some(Category::Numeric),
+            PrimitiveType::Bigint => Some(Category::Bigint),
+            PrimitiveType::DOMString | PrimitiveType::ByteString | PrimitiveType::USVString => {
+                Some(Category::String)
+            }
+            PrimitiveType::Object => Some(Category::Object),
+            PrimitiveType::Symbol => Some(Category::Symbol),
+            _ => Some(Category::InterfaceLike(&standard.name)),
+        }
+    }
+
+    fn is_distinguishable_from(&self, other: &Category, symbols: &SymbolTable) -> bool {
+        match (self, other) {
+            // Interface-like types are distinguishable if no object can implement both of them.
+            (Category::InterfaceLike(a), Category::InterfaceLike(b)) => match (a, b) {
+                _ if a == b => false,
+                (StandardTypeName::Identifier(a), StandardTypeName::Identifier(b)) => {
+                    !inherits(a, b, symbols) && !inherits(b, a, symbols)
+                }
+                _ => true,
+            },
+            // Only callback functions with `[LegacyTreatNonObjectAsNull]` accept objects that
+            // aren't callable, such as dictionaries.
+            (Category::CallbackFunction(legacy), Category::DictionaryLike)
+            | (Category::DictionaryLike, Category::CallbackFunction(legacy)) => !*legacy,
+            (Category::Undefined, Category::DictionaryLike)
+            | (Category::DictionaryLike, Category::Undefined) => false,
+            (Category::Object, other) | (other, Category::Object) => !matches!(
+                other,
+                Category::Object
+                    | Category::InterfaceLike(_)
+                    | Category::CallbackFunction(_)
+                    | Category::DictionaryLike
+                    | Category::SequenceLike
+            ),
+            _ => mem::discriminant(self) != mem::discriminant(other),
+        }
+    }
+}
+
+// Whether the interface `a` inherits from `b`.
+pub(crate) fn inherits(a: &str, b: &str, symbols: &SymbolTable) -> bool {
+    symbols
+        .ancestors(a)
+        .unwrap_or_default()
+        .iter()
+        .any(|ancestor| ancestor.identifier == b)
+}
@@ -0,0 +1,220 @@
+use std::{collections::HashMap, fmt, ops::Range};
+
+use crate::{parser::CodeChars, Definition, Span};
+
+/// Definitions together with the input they were parsed from, see
+/// [`parse_lossless`](crate::parse_lossless).
+///
+/// Comments and whitespace belong to the definition or member they precede, or to the one they
+/// follow on the same line. When printing, definitions and members that weren't modified are
+/// copied from the input as they are, including their comments, while modified or new ones are
+/// formatted like [`to_string`](crate::to_string) does. Removing a definition or member also
+/// removes its comments.
+#[derive(Debug, Clone)]
+pub struct Document {
+    pub definitions: Vec<Definition>,
+    pub(crate) source: String,
+    // Parsed definitions and members by the start of their span.
+    pub(crate) nodes: HashMap<usize, Node>,
+    // Trivia after the last definition.
+    pub(crate) trailing: Range<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Node {
+    pub(crate) end: usize,
+    pub(crate) leading: Range<usize>,
+    pub(crate) trailing: Range<usize>,
+    // The node as printed by `Display` right after parsing, to find out if it was modified.
+    pub(crate) canonical: String,
+    pub(crate) body: Option<Body>,
+}
+
+// Body of a definition with members.
+#[derive(Debug, Clone)]
+pub(crate) struct Body {
+    // Everything up to and including `{`.
+    pub(crate) header: Range<usize>,
+    pub(crate) canonical_header: String,
+    // Trivia after the last member, up to `}`.
+    pub(crate) trailing: Range<usize>,
+}
+
+/* Functionality implementations */
+
+impl Document {
+    pub(crate) fn new(source: &str, definitions: Vec<Definition>) -> Self {
+        let mut nodes = HashMap::new();
+        let mut prev_end = 0;
+
+        for definition in &definitions {
+            let Some(span) = definition.get_span() else {
+                continue;
+            };
+            let leading = prev_end..span.start;
+            let trailing = trailing_trivia(source, span.end);
+            prev_end = trailing.end;
+
+            let body = body_members(definition).map(|members| {
+                let open = body_start(&source[span.start..span.end]) + span.start;
+                let mut prev_end = open;
+
+                for (span, member) in members {
+                    let Some(span) = span else {
+                        continue;
+                    };
+                    let trailing = trailing_trivia(source, span.end);
+                    nodes.insert(
+                        span.start,
+                        Node {
+                            end: span.end,
+                            leading: prev_end..span.start,
+                            trailing: trailing.clone(),
+                            canonical: member.to_string(),
+                            body: None,
+                        },
+                    );
+                    prev_end = trailing.end;
+                }
+
+                Body {
+                    header: span.start..open,
+                    canonical_header: header(definition),
+                    trailing: prev_end..body_end(&source[..span.end]),
+                }
+            });
+
+            nodes.insert(
+                span.start,
+                Node {
+                    end: span.end,
+                    leading,
+                    trailing,
+                    canonical: definition.to_string(),
+                    body,
+                },
+            );
+        }
+
+        Document {
+            definitions,
+            source: source.to_string(),
+            nodes,
+            trailing: prev_end..source.len(),
+        }
+    }
+
+    /// Comments and whitespace in front of the node with the given span, if it was parsed from
+    /// this document's input.
+    pub fn leading_trivia(&self, span: &Span) -> Option<&str> {
+        self.node(span)
+            .map(|node| &self.source[node.leading.clone()])
+    }
+
+    /// Comments on the same line after the node with the given span, if it was parsed from this
+    /// document's input.
+    pub fn trailing_trivia(&self, span: &Span) -> Option<&str> {
+        self.node(span)
+            .map(|node| &self.source[node.trailing.clone()])
+    }
+
+    pub(crate) fn node(&self, span: &Span) -> Option<&Node> {
+        self.nodes
+            .get(&span.start)
+            .filter(|node| node.end == span.end)
+    }
+}
+
+// The members of a definition with a body, with their spans.
+pub(crate) fn body_members(
+    definition: &Definition,
+) -> Option<Vec<(Option<&Span>, &dyn fmt::Display)>> {
+    fn members<T: fmt::Display>(
+        members: &[T],
+        span: fn(&T) -> Option<&Span>,
+    ) -> Vec<(Option<&Span>, &dyn fmt::Display)> {
+        members
+            .iter()
+            .map(|member| (span(member), member as &dyn fmt::Display))
+            .collect()
+    }
+
+    match definition {
+        Definition::Interface(interface) => Some(members(&interface.members, |m| m.get_span())),
+        Definition::InterfaceMixin(interface_mixin) => {
+            Some(members(&interface_mixin.members, |m| m.get_span()))
+        }
+        Definition::CallbackInterface(cb_interface) => {
+            Some(members(&cb_interface.members, |m| m.get_span()))
+        }
+        Definition::Namespace(namespace) => Some(members(&namespace.members, |m| m.get_span())),
+        Definition::Dictionary(dictionary) => {
+            Some(members(&dictionary.members, |m| m.span.as_ref()))
+        }
+        _ => None,
+    }
+}
+
+// A definition with a body as printed by `Display`, up to and including `{`.
+pub(crate) fn header(definition: &Definition) -> String {
+    let mut definition = definition.clone();
+    match &mut definition {
+        Definition::Interface(interface) => interface.members.clear(),
+        Definition::InterfaceMixin(interface_mixin) => interface_mixin.members.clear(),
+        Definition::CallbackInterface(cb_interface) => cb_interface.members.clear(),
+        Definition::Namespace(namespace) => namespace.members.clear(),
+        Definition::Dictionary(dictionary) => dictionary.members.clear(),
+        _ => {}
+    }
+
+    let printed = definition.to_string();
+    match printed.strip_suffix("\n};") {
+        Some(header) => header.to_string(),
+        None => printed,
+    }
+}
+
+// Offset right after the `{` opening the body of a definition.
+fn body_start(definition: &str) -> usize {
+    let mut depth = 0usize;
+
+    for (i, c) in CodeChars::new(definition) {
+        match c {
+            '{' if depth == 0 => return i + 1,
+            '(' | '[' | '<' => depth += 1,
+            ')' | ']' | '>' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    definition.len()
+}
+
+// Offset of the `}` closing the body of the definition that ends at the end of `input`.
+fn body_end(input: &str) -> usize {
+    input.rfind('}').unwrap_or(input.len())
+}
+
+// Range of the spaces and comments following `end` on the same line, if there are any comments.
+fn trailing_trivia(source: &str, end: usize) -> Range<usize> {
+    let mut pos = end;
+    let mut trivia_end = end;
+
+    loop {
+        let rest = &source[pos..];
+        let spaces = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+        let rest = &rest[spaces..];
+
+        if rest.starts_with("//") {
+            let len = rest.find('\n').unwrap_or(rest.len());
+            return end..pos + spaces + len;
+        }
+        match rest.strip_prefix("/*").and_then(|r| r.find("*/")) {
+            Some(len) if !rest[..len + 4].contains('\n') => {
+                pos += spaces + len + 4;
+                trivia_end = pos;
+            }
+            _ => return end..trivia_end,
+        }
+    }
+}
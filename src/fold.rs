@@ -0,0 +1,679 @@
+use crate::{
+    Argument, AttrSpecial, Attribute, CallbackFunction, CallbackInterface, ConstValue, Constant,
+    Constructor, DecimalLiteral, DefaultValue, Definition, Dictionary, DictionaryMember,
+    Enumeration, ExtAttrValue, ExtendedAttribute, FrozenArrayType, Includes, IntegerLiteral,
+    Interface, InterfaceMixin, Iterable, Maplike, Member, NamedArgumentList, Namespace,
+    ObservableArrayType, OpSpecial, Operation, PrimitiveType, PromiseType, RecordType,
+    RecordTypeKey, SequenceType, Setlike, Span, StandardType, StandardTypeName, StringLiteral,
+    Stringifer, Type, Typedef, UnionType,
+};
+
+/// Transformation of the definitions by value, rebuilding them node by node.
+///
+/// Every method folds the nodes its node is made of, by calling the free function of the same
+/// name in this module, and returns the rebuilt node. Override the methods of the nodes you want
+/// to replace, and call that function from the override to keep folding the nodes inside:
+///
+/// ```
+/// use webidl_rs::{fold::{self, Fold}, ExtendedAttribute, StandardType, StandardTypeName};
+///
+/// // Drops vendor extended attributes, and renames `Foo` to `Bar`.
+/// struct Rewrite;
+///
+/// impl Fold for Rewrite {
+///     fn fold_ext_attrs(&mut self, node: Vec<ExtendedAttribute>) -> Vec<ExtendedAttribute> {
+///         let node = node.into_iter().filter(|ext_attr| !ext_attr.identifier.starts_with("Moz"));
+///         fold::fold_ext_attrs(self, node.collect())
+///     }
+///
+///     fn fold_standard_type(&mut self, mut node: StandardType) -> StandardType {
+///         if node.name == StandardTypeName::Identifier("Foo".into()) {
+///             node.name = StandardTypeName::Identifier("Bar".into());
+///         }
+///         fold::fold_standard_type(self, node)
+///     }
+/// }
+///
+/// let definitions = webidl_rs::parse("[MozOnly, Exposed=Window] interface A { attribute Foo? foo; };").unwrap();
+/// let definitions: Vec<_> = definitions
+///     .into_iter()
+///     .map(|definition| Rewrite.fold_definition(definition))
+///     .collect();
+/// assert!(definitions == webidl_rs::parse("[Exposed=Window] interface A { attribute Bar? foo; };").unwrap());
+/// ```
+pub trait Fold {
+    fn fold_definition(&mut self, node: Definition) -> Definition {
+        fold_definition(self, node)
+    }
+
+    fn fold_interface(&mut self, node: Interface) -> Interface {
+        fold_interface(self, node)
+    }
+
+    fn fold_interface_mixin(&mut self, node: InterfaceMixin) -> InterfaceMixin {
+        fold_interface_mixin(self, node)
+    }
+
+    fn fold_includes(&mut self, node: Includes) -> Includes {
+        fold_includes(self, node)
+    }
+
+    fn fold_callback_interface(&mut self, node: CallbackInterface) -> CallbackInterface {
+        fold_callback_interface(self, node)
+    }
+
+    fn fold_namespace(&mut self, node: Namespace) -> Namespace {
+        fold_namespace(self, node)
+    }
+
+    fn fold_dictionary(&mut self, node: Dictionary) -> Dictionary {
+        fold_dictionary(self, node)
+    }
+
+    fn fold_enumeration(&mut self, node: Enumeration) -> Enumeration {
+        fold_enumeration(self, node)
+    }
+
+    fn fold_callback_function(&mut self, node: CallbackFunction) -> CallbackFunction {
+        fold_callback_function(self, node)
+    }
+
+    fn fold_typedef(&mut self, node: Typedef) -> Typedef {
+        fold_typedef(self, node)
+    }
+
+    fn fold_dictionary_member(&mut self, node: DictionaryMember) -> DictionaryMember {
+        fold_dictionary_member(self, node)
+    }
+
+    fn fold_extended_attribute(&mut self, node: ExtendedAttribute) -> ExtendedAttribute {
+        fold_extended_attribute(self, node)
+    }
+
+    /// Folds the extended attributes of a node. Override this to add or remove extended
+    /// attributes, rather than only change them.
+    fn fold_ext_attrs(&mut self, node: Vec<ExtendedAttribute>) -> Vec<ExtendedAttribute> {
+        fold_ext_attrs(self, node)
+    }
+
+    fn fold_ext_attr_value(&mut self, node: ExtAttrValue) -> ExtAttrValue {
+        fold_ext_attr_value(self, node)
+    }
+
+    fn fold_named_argument_list(&mut self, node: NamedArgumentList) -> NamedArgumentList {
+        fold_named_argument_list(self, node)
+    }
+
+    fn fold_argument(&mut self, node: Argument) -> Argument {
+        fold_argument(self, node)
+    }
+
+    fn fold_default_value(&mut self, node: DefaultValue) -> DefaultValue {
+        fold_default_value(self, node)
+    }
+
+    fn fold_member(&mut self, node: Member) -> Member {
+        fold_member(self, node)
+    }
+
+    fn fold_constant(&mut self, node: Constant) -> Constant {
+        fold_constant(self, node)
+    }
+
+    fn fold_const_value(&mut self, node: ConstValue) -> ConstValue {
+        fold_const_value(self, node)
+    }
+
+    fn fold_attribute(&mut self, node: Attribute) -> Attribute {
+        fold_attribute(self, node)
+    }
+
+    fn fold_attr_special(&mut self, node: AttrSpecial) -> AttrSpecial {
+        fold_attr_special(self, node)
+    }
+
+    fn fold_operation(&mut self, node: Operation) -> Operation {
+        fold_operation(self, node)
+    }
+
+    fn fold_op_special(&mut self, node: OpSpecial) -> OpSpecial {
+        fold_op_special(self, node)
+    }
+
+    fn fold_constructor(&mut self, node: Constructor) -> Constructor {
+        fold_constructor(self, node)
+    }
+
+    fn fold_stringifer(&mut self, node: Stringifer) -> Stringifer {
+        fold_stringifer(self, node)
+    }
+
+    fn fold_iterable(&mut self, node: Iterable) -> Iterable {
+        fold_iterable(self, node)
+    }
+
+    fn fold_maplike(&mut self, node: Maplike) -> Maplike {
+        fold_maplike(self, node)
+    }
+
+    fn fold_setlike(&mut self, node: Setlike) -> Setlike {
+        fold_setlike(self, node)
+    }
+
+    fn fold_type(&mut self, node: Type) -> Type {
+        fold_type(self, node)
+    }
+
+    fn fold_sequence_type(&mut self, node: SequenceType) -> SequenceType {
+        fold_sequence_type(self, node)
+    }
+
+    fn fold_union_type(&mut self, node: UnionType) -> UnionType {
+        fold_union_type(self, node)
+    }
+
+    fn fold_record_type(&mut self, node: RecordType) -> RecordType {
+        fold_record_type(self, node)
+    }
+
+    fn fold_record_type_key(&mut self, node: RecordTypeKey) -> RecordTypeKey {
+        fold_record_type_key(self, node)
+    }
+
+    fn fold_promise_type(&mut self, node: PromiseType) -> PromiseType {
+        fold_promise_type(self, node)
+    }
+
+    fn fold_frozen_array_type(&mut self, node: FrozenArrayType) -> FrozenArrayType {
+        fold_frozen_array_type(self, node)
+    }
+
+    fn fold_observable_array_type(&mut self, node: ObservableArrayType) -> ObservableArrayType {
+        fold_observable_array_type(self, node)
+    }
+
+    fn fold_standard_type(&mut self, node: StandardType) -> StandardType {
+        fold_standard_type(self, node)
+    }
+
+    fn fold_standard_type_name(&mut self, node: StandardTypeName) -> StandardTypeName {
+        fold_standard_type_name(self, node)
+    }
+
+    fn fold_primitive_type(&mut self, node: PrimitiveType) -> PrimitiveType {
+        fold_primitive_type(self, node)
+    }
+
+    fn fold_integer_literal(&mut self, node: IntegerLiteral) -> IntegerLiteral {
+        fold_integer_literal(self, node)
+    }
+
+    fn fold_decimal_literal(&mut self, node: DecimalLiteral) -> DecimalLiteral {
+        fold_decimal_literal(self, node)
+    }
+
+    fn fold_string_literal(&mut self, node: StringLiteral) -> StringLiteral {
+        fold_string_literal(self, node)
+    }
+
+    fn fold_span(&mut self, node: Span) -> Span {
+        fold_span(self, node)
+    }
+}
+
+pub fn fold_definition<F: Fold + ?Sized>(f: &mut F, node: Definition) -> Definition {
+    match node {
+        Definition::Interface(node) => Definition::Interface(f.fold_interface(node)),
+        Definition::InterfaceMixin(node) => {
+            Definition::InterfaceMixin(f.fold_interface_mixin(node))
+        }
+        Definition::Includes(node) => Definition::Includes(f.fold_includes(node)),
+        Definition::CallbackInterface(node) => {
+            Definition::CallbackInterface(f.fold_callback_interface(node))
+        }
+        Definition::Namespace(node) => Definition::Namespace(f.fold_namespace(node)),
+        Definition::Dictionary(node) => Definition::Dictionary(f.fold_dictionary(node)),
+        Definition::Enumeration(node) => Definition::Enumeration(f.fold_enumeration(node)),
+        Definition::CallbackFunction(node) => {
+            Definition::CallbackFunction(f.fold_callback_function(node))
+        }
+        Definition::Typedef(node) => Definition::Typedef(f.fold_typedef(node)),
+    }
+}
+
+pub fn fold_interface<F: Fold + ?Sized>(f: &mut F, node: Interface) -> Interface {
+    Interface {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        members: node
+            .members
+            .into_iter()
+            .map(|node| f.fold_member(node))
+            .collect(),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_interface_mixin<F: Fold + ?Sized>(f: &mut F, node: InterfaceMixin) -> InterfaceMixin {
+    InterfaceMixin {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        members: node
+            .members
+            .into_iter()
+            .map(|node| f.fold_member(node))
+            .collect(),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_includes<F: Fold + ?Sized>(f: &mut F, node: Includes) -> Includes {
+    Includes {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_callback_interface<F: Fold + ?Sized>(
+    f: &mut F,
+    node: CallbackInterface,
+) -> CallbackInterface {
+    CallbackInterface {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        members: node
+            .members
+            .into_iter()
+            .map(|node| f.fold_member(node))
+            .collect(),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_namespace<F: Fold + ?Sized>(f: &mut F, node: Namespace) -> Namespace {
+    Namespace {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        members: node
+            .members
+            .into_iter()
+            .map(|node| f.fold_member(node))
+            .collect(),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_dictionary<F: Fold + ?Sized>(f: &mut F, node: Dictionary) -> Dictionary {
+    Dictionary {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        members: node
+            .members
+            .into_iter()
+            .map(|node| f.fold_dictionary_member(node))
+            .collect(),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_enumeration<F: Fold + ?Sized>(f: &mut F, node: Enumeration) -> Enumeration {
+    Enumeration {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        values: node
+            .values
+            .into_iter()
+            .map(|node| f.fold_string_literal(node))
+            .collect(),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_callback_function<F: Fold + ?Sized>(
+    f: &mut F,
+    node: CallbackFunction,
+) -> CallbackFunction {
+    CallbackFunction {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        r#type: f.fold_type(node.r#type),
+        arguments: node
+            .arguments
+            .into_iter()
+            .map(|node| f.fold_argument(node))
+            .collect(),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_typedef<F: Fold + ?Sized>(f: &mut F, node: Typedef) -> Typedef {
+    Typedef {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        r#type: f.fold_type(node.r#type),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_dictionary_member<F: Fold + ?Sized>(
+    f: &mut F,
+    node: DictionaryMember,
+) -> DictionaryMember {
+    DictionaryMember {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        r#type: f.fold_type(node.r#type),
+        default: node.default.map(|node| f.fold_default_value(node)),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_extended_attribute<F: Fold + ?Sized>(
+    f: &mut F,
+    node: ExtendedAttribute,
+) -> ExtendedAttribute {
+    ExtendedAttribute {
+        value: node.value.map(|node| f.fold_ext_attr_value(node)),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_ext_attrs<F: Fold + ?Sized>(
+    f: &mut F,
+    node: Vec<ExtendedAttribute>,
+) -> Vec<ExtendedAttribute> {
+    node.into_iter()
+        .map(|node| f.fold_extended_attribute(node))
+        .collect()
+}
+
+pub fn fold_ext_attr_value<F: Fold + ?Sized>(f: &mut F, node: ExtAttrValue) -> ExtAttrValue {
+    match node {
+        ExtAttrValue::ArgumentList(nodes) => ExtAttrValue::ArgumentList(
+            nodes
+                .into_iter()
+                .map(|node| f.fold_argument(node))
+                .collect(),
+        ),
+        ExtAttrValue::NamedArgumentList(node) => {
+            ExtAttrValue::NamedArgumentList(f.fold_named_argument_list(node))
+        }
+        ExtAttrValue::String(node) => ExtAttrValue::String(f.fold_string_literal(node)),
+        ExtAttrValue::StringList(nodes) => ExtAttrValue::StringList(
+            nodes
+                .into_iter()
+                .map(|node| f.fold_string_literal(node))
+                .collect(),
+        ),
+        node => node,
+    }
+}
+
+pub fn fold_named_argument_list<F: Fold + ?Sized>(
+    f: &mut F,
+    node: NamedArgumentList,
+) -> NamedArgumentList {
+    NamedArgumentList {
+        arguments: node
+            .arguments
+            .into_iter()
+            .map(|node| f.fold_argument(node))
+            .collect(),
+        ..node
+    }
+}
+
+pub fn fold_argument<F: Fold + ?Sized>(f: &mut F, node: Argument) -> Argument {
+    Argument {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        r#type: f.fold_type(node.r#type),
+        default: node.default.map(|node| f.fold_default_value(node)),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_default_value<F: Fold + ?Sized>(f: &mut F, node: DefaultValue) -> DefaultValue {
+    match node {
+        DefaultValue::Integer(node) => DefaultValue::Integer(f.fold_integer_literal(node)),
+        DefaultValue::Decimal(node) => DefaultValue::Decimal(f.fold_decimal_literal(node)),
+        DefaultValue::String(node) => DefaultValue::String(f.fold_string_literal(node)),
+        node => node,
+    }
+}
+
+pub fn fold_member<F: Fold + ?Sized>(f: &mut F, node: Member) -> Member {
+    match node {
+        Member::Constant(node) => Member::Constant(f.fold_constant(node)),
+        Member::Attribute(node) => Member::Attribute(f.fold_attribute(node)),
+        Member::Operation(node) => Member::Operation(f.fold_operation(node)),
+        Member::Constructor(node) => Member::Constructor(f.fold_constructor(node)),
+        Member::Stringifer(node) => Member::Stringifer(f.fold_stringifer(node)),
+        Member::Iterable(node) => Member::Iterable(f.fold_iterable(node)),
+        Member::Maplike(node) => Member::Maplike(f.fold_maplike(node)),
+        Member::Setlike(node) => Member::Setlike(f.fold_setlike(node)),
+    }
+}
+
+pub fn fold_constant<F: Fold + ?Sized>(f: &mut F, node: Constant) -> Constant {
+    Constant {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        r#type: f.fold_type(node.r#type),
+        value: f.fold_const_value(node.value),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_const_value<F: Fold + ?Sized>(f: &mut F, node: ConstValue) -> ConstValue {
+    match node {
+        ConstValue::Integer(node) => ConstValue::Integer(f.fold_integer_literal(node)),
+        ConstValue::Decimal(node) => ConstValue::Decimal(f.fold_decimal_literal(node)),
+        node => node,
+    }
+}
+
+pub fn fold_attribute<F: Fold + ?Sized>(f: &mut F, node: Attribute) -> Attribute {
+    Attribute {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        special: node.special.map(|node| f.fold_attr_special(node)),
+        r#type: f.fold_type(node.r#type),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_attr_special<F: Fold + ?Sized>(_f: &mut F, node: AttrSpecial) -> AttrSpecial {
+    node
+}
+
+pub fn fold_operation<F: Fold + ?Sized>(f: &mut F, node: Operation) -> Operation {
+    Operation {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        special: node.special.map(|node| f.fold_op_special(node)),
+        r#type: f.fold_type(node.r#type),
+        arguments: node
+            .arguments
+            .into_iter()
+            .map(|node| f.fold_argument(node))
+            .collect(),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_op_special<F: Fold + ?Sized>(_f: &mut F, node: OpSpecial) -> OpSpecial {
+    node
+}
+
+pub fn fold_constructor<F: Fold + ?Sized>(f: &mut F, node: Constructor) -> Constructor {
+    Constructor {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        arguments: node
+            .arguments
+            .into_iter()
+            .map(|node| f.fold_argument(node))
+            .collect(),
+        span: node.span.map(|node| f.fold_span(node)),
+    }
+}
+
+pub fn fold_stringifer<F: Fold + ?Sized>(f: &mut F, node: Stringifer) -> Stringifer {
+    Stringifer {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        span: node.span.map(|node| f.fold_span(node)),
+    }
+}
+
+pub fn fold_iterable<F: Fold + ?Sized>(f: &mut F, node: Iterable) -> Iterable {
+    Iterable {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        key_type: node.key_type.map(|node| f.fold_type(node)),
+        value_type: f.fold_type(node.value_type),
+        arguments: node.arguments.map(|nodes| {
+            nodes
+                .into_iter()
+                .map(|node| f.fold_argument(node))
+                .collect()
+        }),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_maplike<F: Fold + ?Sized>(f: &mut F, node: Maplike) -> Maplike {
+    Maplike {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        key_type: f.fold_type(node.key_type),
+        value_type: f.fold_type(node.value_type),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_setlike<F: Fold + ?Sized>(f: &mut F, node: Setlike) -> Setlike {
+    Setlike {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        r#type: f.fold_type(node.r#type),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_type<F: Fold + ?Sized>(f: &mut F, node: Type) -> Type {
+    match node {
+        Type::Sequence(node) => Type::Sequence(f.fold_sequence_type(node)),
+        Type::Record(node) => Type::Record(f.fold_record_type(node)),
+        Type::Promise(node) => Type::Promise(f.fold_promise_type(node)),
+        Type::Union(node) => Type::Union(f.fold_union_type(node)),
+        Type::FrozenArray(node) => Type::FrozenArray(f.fold_frozen_array_type(node)),
+        Type::ObservableArray(node) => Type::ObservableArray(f.fold_observable_array_type(node)),
+        Type::Standard(node) => Type::Standard(f.fold_standard_type(node)),
+    }
+}
+
+pub fn fold_sequence_type<F: Fold + ?Sized>(f: &mut F, node: SequenceType) -> SequenceType {
+    SequenceType {
+        r#type: Box::new(f.fold_type(*node.r#type)),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_union_type<F: Fold + ?Sized>(f: &mut F, node: UnionType) -> UnionType {
+    UnionType {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        types: node
+            .types
+            .into_iter()
+            .map(|node| f.fold_type(node))
+            .collect(),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_record_type<F: Fold + ?Sized>(f: &mut F, node: RecordType) -> RecordType {
+    RecordType {
+        key: f.fold_record_type_key(node.key),
+        value: Box::new(f.fold_type(*node.value)),
+        span: node.span.map(|node| f.fold_span(node)),
+    }
+}
+
+pub fn fold_record_type_key<F: Fold + ?Sized>(_f: &mut F, node: RecordTypeKey) -> RecordTypeKey {
+    node
+}
+
+pub fn fold_promise_type<F: Fold + ?Sized>(f: &mut F, node: PromiseType) -> PromiseType {
+    PromiseType {
+        r#type: Box::new(f.fold_type(*node.r#type)),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_frozen_array_type<F: Fold + ?Sized>(
+    f: &mut F,
+    node: FrozenArrayType,
+) -> FrozenArrayType {
+    FrozenArrayType {
+        r#type: Box::new(f.fold_type(*node.r#type)),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_observable_array_type<F: Fold + ?Sized>(
+    f: &mut F,
+    node: ObservableArrayType,
+) -> ObservableArrayType {
+    ObservableArrayType {
+        r#type: Box::new(f.fold_type(*node.r#type)),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_standard_type<F: Fold + ?Sized>(f: &mut F, node: StandardType) -> StandardType {
+    StandardType {
+        ext_attrs: f.fold_ext_attrs(node.ext_attrs),
+        name: f.fold_standard_type_name(node.name),
+        span: node.span.map(|node| f.fold_span(node)),
+        ..node
+    }
+}
+
+pub fn fold_standard_type_name<F: Fold + ?Sized>(
+    f: &mut F,
+    node: StandardTypeName,
+) -> StandardTypeName {
+    match node {
+        StandardTypeName::Primitive(node) => {
+            StandardTypeName::Primitive(f.fold_primitive_type(node))
+        }
+        node => node,
+    }
+}
+
+pub fn fold_primitive_type<F: Fold + ?Sized>(_f: &mut F, node: PrimitiveType) -> PrimitiveType {
+    node
+}
+
+pub fn fold_integer_literal<F: Fold + ?Sized>(_f: &mut F, node: IntegerLiteral) -> IntegerLiteral {
+    node
+}
+
+pub fn fold_decimal_literal<F: Fold + ?Sized>(_f: &mut F, node: DecimalLiteral) -> DecimalLiteral {
+    node
+}
+
+pub fn fold_string_literal<F: Fold + ?Sized>(_f: &mut F, node: StringLiteral) -> StringLiteral {
+    node
+}
+
+pub fn fold_span<F: Fold + ?Sized>(_f: &mut F, node: Span) -> Span {
+    node
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+
+use crate::{
+    Dictionary, DictionaryMember, Effective, EffectiveDefinitions, Interface, Member, Provenance,
+    Symbol, SymbolTable,
+};
+
+/// Error of the inheritance queries: an interface or dictionary inherits from itself, directly or
+/// through its ancestors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InheritanceCycle {
+    /// Identifiers of the definitions in the cycle, starting and ending with the same one.
+    pub identifiers: Vec<String>,
+}
+
+/// A member of an [`Effective`] interface or dictionary, or of one of its ancestors.
+#[derive(Debug, Clone, Copy)]
+pub struct InheritedMember<'a, M> {
+    /// Identifier of the interface or dictionary the member belongs to.
+    pub definition: &'a str,
+    pub member: &'a M,
+    pub provenance: &'a Provenance,
+}
+
+/* Functionality implementations */
+
+impl<'a> SymbolTable<'a> {
+    /// The symbols of the interfaces or dictionaries the given one inherits from, nearest first.
+    /// The chain ends at the first ancestor that isn't defined, or that is of a different kind.
+    pub fn ancestors(&self, identifier: &str) -> Result<Vec<&Symbol<'a>>, InheritanceCycle> {
+        let parent = |identifier: &str| {
+            let symbol = self.get(identifier)?;
+            let parent = self.get(symbol.definition?.get_inheritance()?)?;
+            (parent.kind == symbol.kind).then_some(parent.identifier)
+        };
+
+        Ok(ancestors(identifier, parent)?
+            .into_iter()
+            .filter_map(|identifier| self.get(identifier))
+            .collect())
+    }
+}
+
+impl EffectiveDefinitions {
+    /// The interfaces the given one inherits from, nearest first. The chain ends at the first
+    /// ancestor that isn't an interface.
+    pub fn interface_ancestors(
+        &self,
+        identifier: &str,
+    ) -> Result<Vec<&Effective<Interface>>, InheritanceCycle> {
+        let parent = |identifier: &str| {
+            let parent = self
+                .interface(identifier)?
+                .definition
+                .inheritance
+                .as_deref()?;
+            self.interface(parent)
+                .map(|parent| parent.definition.identifier.as_str())
+        };
+
+        Ok(ancestors(identifier, parent)?
+            .into_iter()
+            .filter_map(|identifier| self.interface(identifier))
+            .collect())
+    }
+
+    /// The dictionaries the given one inherits from, nearest first. The chain ends at the first
+    /// ancestor that isn't a dictionary.
+    pub fn dictionary_ancestors(
+        &self,
+        identifier: &str,
+    ) -> Result<Vec<&Effective<Dictionary>>, InheritanceCycle> {
+        let parent = |identifier: &str| {
+            let parent = self
+                .dictionary(identifier)?
+                .definition
+                .inheritance
+                .as_deref()?;
+            self.dictionary(parent)
+                .map(|parent| parent.definition.identifier.as_str())
+        };
+
+        Ok(ancestors(identifier, parent)?
+            .into_iter()
+            .filter_map(|identifier| self.dictionary(identifier))
+            .collect())
+    }
+
+    /// The members of the interface and all the interfaces it inherits from, starting with those
+    /// of the topmost ancestor. Members of each interface are in the order they were declared in.
+    pub fn interface_members(
+        &self,
+        identifier: &str,
+    ) -> Result<Vec<InheritedMember<'_, Member>>, InheritanceCycle> {
+        let mut interfaces = self.interface_ancestors(identifier)?;
+        interfaces.reverse();
+        interfaces.extend(self.interface(identifier));
+
+        Ok(interfaces
+            .into_iter()
+            .flat_map(|interface| {
+                let definition = &interface.definition;
+                inherited_members(
+                    &definition.identifier,
+                    &definition.members,
+                    &interface.provenance,
+                )
+            })
+            .collect())
+    }
+
+    /// The dictionary members of the dictionary, as defined in:
+    /// <https://webidl.spec.whatwg.org/#dfn-dictionary-members>
+    ///
+    /// Those of the topmost ancestor come first, and the members of each dictionary are ordered
+    /// lexicographically by their identifier.
+    pub fn dictionary_members(
+        &self,
+        identifier: &str,
+    ) -> Result<Vec<InheritedMember<'_, DictionaryMember>>, InheritanceCycle> {
+        let mut dictionaries = self.dictionary_ancestors(identifier)?;
+        dictionaries.reverse();
+        dictionaries.extend(self.dictionary(identifier));
+
+        Ok(dictionaries
+            .into_iter()
+            .flat_map(|dictionary| {
+                let definition = &dictionary.definition;
+                let mut members: Vec<_> = inherited_members(
+                    &definition.identifier,
+                    &definition.members,
+                    &dictionary.provenance,
+                )
+                .collect();
+                members.sort_by(|a, b| a.member.identifier.cmp(&b.member.identifier));
+                members
+            })
+            .collect())
+    }
+}
+
+// Follows `parent` from `identifier`, returning the identifiers of the ancestors, nearest first.
+fn ancestors<'a>(
+    identifier: &str,
+    parent: impl Fn(&str) -> Option<&'a str>,
+) -> Result<Vec<&'a str>, InheritanceCycle> {
+    let mut chain: Vec<&str> = vec![];
+    let mut current = parent(identifier);
+
+    while let Some(ancestor) = current {
+        let visited = || std::iter::once(identifier).chain(chain.iter().copied());
+        if let Some(start) = visited().position(|visited| visited == ancestor) {
+            let mut identifiers: Vec<_> = visited().skip(start).map(str::to_string).collect();
+            identifiers.push(ancestor.to_string());
+            return Err(InheritanceCycle { identifiers });
+        }
+
+        chain.push(ancestor);
+        current = parent(ancestor);
+    }
+
+    Ok(chain)
+}
+
+fn inherited_members<'a, M>(
+    definition: &'a str,
+    members: &'a [M],
+    provenance: &'a [Provenance],
+) -> impl Iterator<Item = InheritedMember<'a, M>> {
+    members
+        .iter()
+        .zip(provenance)
+        .map(move |(member, provenance)| InheritedMember {
+            definition,
+            member,
+            provenance,
+        })
+}
+
+/* Trait implementations */
+
+impl fmt::Display for InheritanceCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "definition inherits from itself: {}",
+            self.identifiers.join(" -> ")
+        )
+    }
+}
+
+impl std::error::Error for InheritanceCycle {}
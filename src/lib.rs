@@ -1,34 +1,127 @@
+#[macro_use]
+mod macros;
+
 /* Web IDL data structures */
 mod definitions;
+mod document;
+mod literals;
 mod members;
+mod span;
 mod types;
 
 pub use definitions::*;
+pub use document::*;
+pub use literals::*;
 pub use members::*;
+pub use span::*;
 pub use types::*;
 
 /* Display and parser logic */
 mod display;
 mod parser;
 
+pub use display::{FormatOptions, IndentStyle, Printer};
+pub use parser::{Lexer, ParseError, ParseErrorKind, Token, TokenKind};
+
+/* Semantic analysis */
+mod distinguishability;
+mod inheritance;
+mod merge;
+mod overloads;
+mod resolution;
+mod symbols;
+mod typedefs;
+mod validation;
+
+pub use inheritance::{InheritanceCycle, InheritedMember};
+pub use merge::{Effective, EffectiveDefinitions, Provenance};
+pub use overloads::{Callable, Optionality, OverloadEntry, OverloadSet};
+pub use resolution::{JsValue, TypeError};
+pub use symbols::{Symbol, SymbolKind, SymbolTable};
+pub use typedefs::{TypedefCycle, TypedefResolver};
+pub use validation::{Diagnostic, Rule, Severity};
+
+/* Interchange formats */
+#[cfg(feature = "webidl2")]
+mod webidl2;
+
+#[cfg(feature = "webidl2")]
+pub use webidl2::Webidl2Error;
+
+/* Traversal */
+pub mod fold;
+pub mod visit;
+pub mod visit_mut;
+
 /* Tests */
 #[cfg(test)]
+#[allow(
+    clippy::bool_comparison,
+    clippy::partialeq_to_none,
+    clippy::needless_pub_self,
+    clippy::needless_borrows_for_generic_args
+)]
 mod tests;
 
 /* Exposed functions */
 use itertools::join;
-use nom::{combinator::all_consuming, error::Error, multi::many0, sequence::terminated, Err};
 
-pub fn parse(input: &str) -> Result<Vec<Definition>, Err<Error<String>>> {
-    let (_, definitions) = all_consuming(terminated(
-        many0(Definition::parse),
-        parser::multispace_or_comment0,
-    ))(input)
-    .map_err(|e| e.to_owned())?;
+/// Parses the input into definitions, or returns the first error. The spans of the parsed nodes
+/// don't refer to a file, see [`parse_with_file`].
+pub fn parse(input: &str) -> Result<Vec<Definition>, ParseError> {
+    parser::parse_definitions(input, None)
+}
+
+/// Like [`parse`], but the spans of the parsed nodes refer to `file`.
+pub fn parse_with_file(input: &str, file: &str) -> Result<Vec<Definition>, ParseError> {
+    parser::parse_definitions(input, Some(file.into()))
+}
+
+/// Like [`parse`], but instead of stopping at the first error, skips the broken definition (or
+/// member, inside of a definition body) and continues after the next `;`. Returns all definitions
+/// that could be parsed, together with the errors of everything that was skipped.
+pub fn parse_recovering(input: &str) -> (Vec<Definition>, Vec<ParseError>) {
+    parser::parse_definitions_recovering(input, None)
+}
+
+/// Like [`parse_recovering`], but the spans of the parsed nodes refer to `file`.
+pub fn parse_recovering_with_file(input: &str, file: &str) -> (Vec<Definition>, Vec<ParseError>) {
+    parser::parse_definitions_recovering(input, Some(file.into()))
+}
 
-    Ok(definitions)
+/// Like [`parse`], but keeps the input around, so that the definitions can be printed again with
+/// their comments and formatting. See [`Document`].
+pub fn parse_lossless(input: &str) -> Result<Document, ParseError> {
+    parser::parse_definitions(input, None).map(|definitions| Document::new(input, definitions))
+}
+
+/// Checks the definitions for violations of the Web IDL specification that the parser doesn't
+/// catch, such as duplicate identifiers or references to undefined types.
+pub fn validate(definitions: &[Definition]) -> Vec<Diagnostic> {
+    validation::validate(definitions)
+}
+
+/// Merges partial definitions into the interfaces, namespaces and dictionaries they extend, and
+/// copies the members of interface mixins into every interface that includes them.
+pub fn merge(definitions: &[Definition]) -> EffectiveDefinitions {
+    merge::merge(definitions)
 }
 
 pub fn to_string(definitions: &[Definition]) -> String {
     join(definitions, "\n\n")
 }
+
+/// Converts the definitions into the JSON AST of [webidl2.js](https://github.com/w3c/webidl2.js),
+/// as consumed by tools such as webref. Like the concrete syntax tree of webidl2.js, the array ends
+/// with an `eof` node.
+#[cfg(feature = "webidl2")]
+pub fn to_webidl2_json(definitions: &[Definition]) -> serde_json::Value {
+    webidl2::to_json(definitions)
+}
+
+/// Converts the JSON AST of [webidl2.js](https://github.com/w3c/webidl2.js) into definitions.
+/// The definitions don't have spans, as the JSON doesn't contain the input.
+#[cfg(feature = "webidl2")]
+pub fn from_webidl2_json(json: &serde_json::Value) -> Result<Vec<Definition>, Webidl2Error> {
+    webidl2::from_json(json)
+}
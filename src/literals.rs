@@ -0,0 +1,116 @@
+use std::hash::{Hash, Hasher};
+
+/// Integer literal, such as `-1` or `0x1F`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntegerLiteral {
+    pub value: i128,
+    /// The literal as written in the input. It's printed instead of `value`, as long as it still
+    /// represents the same value.
+    pub raw: Option<String>,
+}
+
+/// Decimal literal, such as `1.0` or `1e3`. Values that aren't finite are printed as `NaN`,
+/// `Infinity` or `-Infinity`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecimalLiteral {
+    pub value: f64,
+    /// The literal as written in the input. It's printed instead of `value`, as long as it still
+    /// represents the same value.
+    pub raw: Option<String>,
+}
+
+/// String literal, such as `"foo"`. Web IDL strings don't have escape sequences, so the value is
+/// exactly the text between the quotes and can't contain `"` itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct StringLiteral(pub(crate) String);
+
+/* Functionality implementations */
+
+impl StringLiteral {
+    /// Returns `None` if `value` contains a `"`, which can't be represented in Web IDL.
+    pub fn new(value: impl Into<String>) -> Option<Self> {
+        let value = value.into();
+        (!value.contains('"')).then_some(StringLiteral(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/* Trait implementations */
+
+// Literals are equal if their values are, no matter how they were written.
+impl PartialEq for IntegerLiteral {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for IntegerLiteral {}
+
+impl Hash for IntegerLiteral {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+// Comparing the bits keeps the equality reflexive even for `NaN`, as `Eq` requires. Unlike with
+// `f64`, `0.0` and `-0.0` aren't equal.
+impl PartialEq for DecimalLiteral {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.to_bits() == other.value.to_bits()
+    }
+}
+
+impl Eq for DecimalLiteral {}
+
+impl Hash for DecimalLiteral {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.to_bits().hash(state);
+    }
+}
+
+impl From<i128> for IntegerLiteral {
+    fn from(value: i128) -> Self {
+        IntegerLiteral { value, raw: None }
+    }
+}
+
+impl From<f64> for DecimalLiteral {
+    fn from(value: f64) -> Self {
+        DecimalLiteral { value, raw: None }
+    }
+}
+
+impl AsRef<str> for StringLiteral {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for StringLiteral {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for StringLiteral {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+// Checked like in `StringLiteral::new`, so that deserializing can't create a literal that can't be
+// printed.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StringLiteral {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        StringLiteral::new(value)
+            .ok_or_else(|| serde::de::Error::custom("string literal contains `\"`"))
+    }
+}
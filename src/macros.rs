@@ -0,0 +1,20 @@
+// Implements `PartialEq`, `Eq` and `Hash` for an AST node by its fields, leaving out its span. All
+// fields besides the span must be listed, otherwise it doesn't compile.
+macro_rules! impl_eq_without_span {
+    ($($node:ident { $($field:ident),* $(,)? })*) => {$(
+        impl PartialEq for $node {
+            fn eq(&self, other: &Self) -> bool {
+                let $node { $($field: _,)* span: _ } = self;
+                true $(&& self.$field == other.$field)*
+            }
+        }
+
+        impl Eq for $node {}
+
+        impl std::hash::Hash for $node {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                $(self.$field.hash(state);)*
+            }
+        }
+    )*};
+}
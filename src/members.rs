@@ -1,6 +1,13 @@
-use crate::{Argument, ExtendedAttribute, Type};
-
-#[derive(Debug, Clone)]
+use crate::{Argument, DecimalLiteral, ExtendedAttribute, IntegerLiteral, Span, Type};
+
+/// With the `serde` feature, members are serialized as `{"kind": "operation", "value": {..}}`, where
+/// `kind` is the variant in snake case.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum Member {
     Constant(Constant),
     Attribute(Attribute),
@@ -13,18 +20,21 @@ pub enum Member {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Constant {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub r#type: Type,
     pub identifier: String,
     pub value: ConstValue,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConstValue {
     Boolean(bool),
-    Integer(i64),
-    Decimal(f64),
+    Integer(IntegerLiteral),
+    Decimal(DecimalLiteral),
 
     NegativeInfinity,
     Infinity,
@@ -32,15 +42,18 @@ pub enum ConstValue {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Attribute {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub readonly: bool,
     pub special: Option<AttrSpecial>,
     pub r#type: Type,
     pub identifier: String,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttrSpecial {
     Static,
     Stringifier,
@@ -48,15 +61,18 @@ pub enum AttrSpecial {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Operation {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub special: Option<OpSpecial>,
     pub r#type: Type,
     pub identifier: String,
     pub arguments: Vec<Argument>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OpSpecial {
     Static,
     Getter,
@@ -64,39 +80,49 @@ pub enum OpSpecial {
     Deleter,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Constructor {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub arguments: Vec<Argument>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Stringifer {
     pub ext_attrs: Vec<ExtendedAttribute>,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Iterable {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub r#async: bool,
     pub key_type: Option<Type>,
     pub value_type: Type,
     pub arguments: Option<Vec<Argument>>,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Maplike {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub readonly: bool,
     pub key_type: Type,
     pub value_type: Type,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Setlike {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub readonly: bool,
     pub r#type: Type,
+    pub span: Option<Span>,
 }
 
 /* Functionality implementations */
@@ -136,9 +162,46 @@ impl Member {
             Member::Setlike(setlike) => &mut setlike.ext_attrs,
         }
     }
+
+    pub fn get_span(&self) -> Option<&Span> {
+        match self {
+            Member::Constant(constant) => constant.span.as_ref(),
+            Member::Attribute(attribute) => attribute.span.as_ref(),
+            Member::Operation(operation) => operation.span.as_ref(),
+            Member::Constructor(constructor) => constructor.span.as_ref(),
+            Member::Stringifer(stringifier) => stringifier.span.as_ref(),
+            Member::Iterable(iterable) => iterable.span.as_ref(),
+            Member::Maplike(maplike) => maplike.span.as_ref(),
+            Member::Setlike(setlike) => setlike.span.as_ref(),
+        }
+    }
+
+    pub fn get_span_mut(&mut self) -> &mut Option<Span> {
+        match self {
+            Member::Constant(constant) => &mut constant.span,
+            Member::Attribute(attribute) => &mut attribute.span,
+            Member::Operation(operation) => &mut operation.span,
+            Member::Constructor(constructor) => &mut constructor.span,
+            Member::Stringifer(stringifier) => &mut stringifier.span,
+            Member::Iterable(iterable) => &mut iterable.span,
+            Member::Maplike(maplike) => &mut maplike.span,
+            Member::Setlike(setlike) => &mut setlike.span,
+        }
+    }
 }
 
 /* Trait implementations */
 
+impl_eq_without_span! {
+    Constant { ext_attrs, r#type, identifier, value }
+    Attribute { ext_attrs, readonly, special, r#type, identifier }
+    Operation { ext_attrs, special, r#type, identifier, arguments }
+    Constructor { ext_attrs, arguments }
+    Stringifer { ext_attrs }
+    Iterable { ext_attrs, r#async, key_type, value_type, arguments }
+    Maplike { ext_attrs, readonly, key_type, value_type }
+    Setlike { ext_attrs, readonly, r#type }
+}
+
 // ...
 // ...
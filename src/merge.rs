@@ -0,0 +1,278 @@
+use std::{collections::HashSet, ptr};
+
+use crate::{
+    Definition, Diagnostic, Dictionary, DictionaryMember, ExtendedAttribute, Includes, Interface,
+    Member, Namespace, Rule, Severity, Span, Symbol, SymbolKind, SymbolTable,
+};
+
+/// A definition merged with its partial definitions and, for interfaces, with the interface mixins
+/// it includes.
+#[derive(Debug, Clone)]
+pub struct Effective<T> {
+    /// The merged definition, which isn't partial. Everything but its members is taken from the
+    /// definition that isn't partial.
+    pub definition: T,
+    /// Where each member of `definition` was declared, in the same order as the members.
+    pub provenance: Vec<Provenance>,
+}
+
+/// Where a member of an [`Effective`] definition was declared.
+#[derive(Debug, Clone)]
+pub struct Provenance {
+    /// Identifier of the interface mixin the member was copied from, if any.
+    pub mixin: Option<String>,
+    /// Position of the partial definition the member was declared in, among the partial
+    /// definitions with the same identifier (those of the mixin, if it was copied from one). `None`
+    /// if it was declared in the definition that isn't partial.
+    pub partial: Option<usize>,
+    /// Extended attributes of the definition the member was declared in, such as `[Exposed]` on a
+    /// partial interface, which apply to the member as well.
+    pub ext_attrs: Vec<ExtendedAttribute>,
+    /// Location of the definition the member was declared in, if it was parsed.
+    pub span: Option<Span>,
+}
+
+/// Result of [`merge`](crate::merge).
+#[derive(Debug, Clone, Default)]
+pub struct EffectiveDefinitions {
+    pub interfaces: Vec<Effective<Interface>>,
+    pub namespaces: Vec<Effective<Namespace>>,
+    pub dictionaries: Vec<Effective<Dictionary>>,
+    /// Partial definitions that don't extend a definition of the same kind, and includes
+    /// statements that don't refer to an interface and an interface mixin. What they would
+    /// contribute is left out.
+    pub errors: Vec<Diagnostic>,
+}
+
+/* Functionality implementations */
+
+impl EffectiveDefinitions {
+    pub fn interface(&self, identifier: &str) -> Option<&Effective<Interface>> {
+        self.interfaces
+            .iter()
+            .find(|interface| interface.definition.identifier == identifier)
+    }
+
+    pub fn namespace(&self, identifier: &str) -> Option<&Effective<Namespace>> {
+        self.namespaces
+            .iter()
+            .find(|namespace| namespace.definition.identifier == identifier)
+    }
+
+    pub fn dictionary(&self, identifier: &str) -> Option<&Effective<Dictionary>> {
+        self.dictionaries
+            .iter()
+            .find(|dictionary| dictionary.definition.identifier == identifier)
+    }
+}
+
+pub(crate) fn merge(definitions: &[Definition]) -> EffectiveDefinitions {
+    let symbols = SymbolTable::new(definitions);
+    let mut merged = EffectiveDefinitions::default();
+
+    for definition in definitions {
+        if let Definition::Includes(includes) = definition {
+            check_includes(&symbols, definition, includes, &mut merged.errors);
+            continue;
+        }
+        let Some(symbol) = definition.get_identifier().and_then(|i| symbols.get(i)) else {
+            continue;
+        };
+
+        if definition.is_partial() {
+            if Some(symbol.kind) != SymbolKind::of(definition) {
+                let kind = match definition {
+                    Definition::Interface(_) => "an interface",
+                    Definition::InterfaceMixin(_) => "an interface mixin",
+                    Definition::Namespace(_) => "a namespace",
+                    _ => "a dictionary",
+                };
+                merged.errors.push(error(
+                    Rule::DuplicateDefinition,
+                    definition,
+                    format!("`{}` is not {}", symbol.identifier, kind),
+                ));
+            } else if symbol.definition.is_none() {
+                merged.errors.push(error(
+                    Rule::MissingDefinition,
+                    definition,
+                    format!("`{}` is only defined as partial", symbol.identifier),
+                ));
+            }
+            continue;
+        }
+        // Only the first definition with an identifier is merged, the others are duplicates.
+        if !symbol.definition.is_some_and(|d| ptr::eq(d, definition)) {
+            continue;
+        }
+
+        match definition {
+            Definition::Interface(interface) => {
+                let mut members = vec![];
+                let mut provenance = vec![];
+                merge_members(
+                    symbol,
+                    None,
+                    interface_members,
+                    &mut members,
+                    &mut provenance,
+                );
+
+                // Every mixin is only included once, no matter how often it's stated.
+                let mut included = HashSet::new();
+                for includes in definitions.iter().filter_map(|d| match d {
+                    Definition::Includes(includes)
+                        if includes.interface == interface.identifier =>
+                    {
+                        Some(includes)
+                    }
+                    _ => None,
+                }) {
+                    let Some(mixin) = symbols.get(&includes.mixin) else {
+                        continue;
+                    };
+                    if mixin.kind != SymbolKind::InterfaceMixin
+                        || mixin.definition.is_none()
+                        || !included.insert(mixin.identifier)
+                    {
+                        continue;
+                    }
+                    merge_members(
+                        mixin,
+                        Some(mixin.identifier),
+                        interface_members,
+                        &mut members,
+                        &mut provenance,
+                    );
+                }
+
+                merged.interfaces.push(Effective {
+                    definition: Interface {
+                        members,
+                        ..interface.clone()
+                    },
+                    provenance,
+                });
+            }
+            Definition::Namespace(namespace) => {
+                let mut members = vec![];
+                let mut provenance = vec![];
+                merge_members(
+                    symbol,
+                    None,
+                    interface_members,
+                    &mut members,
+                    &mut provenance,
+                );
+
+                merged.namespaces.push(Effective {
+                    definition: Namespace {
+                        members,
+                        ..namespace.clone()
+                    },
+                    provenance,
+                });
+            }
+            Definition::Dictionary(dictionary) => {
+                let mut members = vec![];
+                let mut provenance = vec![];
+                merge_members(
+                    symbol,
+                    None,
+                    dictionary_members,
+                    &mut members,
+                    &mut provenance,
+                );
+
+                merged.dictionaries.push(Effective {
+                    definition: Dictionary {
+                        members,
+                        ..dictionary.clone()
+                    },
+                    provenance,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    merged
+}
+
+// Appends the members of all definitions of `symbol`, which must have a definition that isn't
+// partial, together with where they were declared.
+fn merge_members<'a, M: Clone>(
+    symbol: &Symbol<'a>,
+    mixin: Option<&str>,
+    members_of: fn(&'a Definition) -> &'a [M],
+    members: &mut Vec<M>,
+    provenance: &mut Vec<Provenance>,
+) {
+    for (index, definition) in symbol.definitions().enumerate() {
+        let declared = Provenance {
+            mixin: mixin.map(str::to_string),
+            partial: index.checked_sub(1),
+            ext_attrs: definition.get_ext_attrs().clone(),
+            span: definition.get_span().cloned(),
+        };
+        for member in members_of(definition) {
+            members.push(member.clone());
+            provenance.push(declared.clone());
+        }
+    }
+}
+
+fn interface_members(definition: &Definition) -> &[Member] {
+    match definition {
+        Definition::Interface(interface) => &interface.members,
+        Definition::InterfaceMixin(interface_mixin) => &interface_mixin.members,
+        Definition::Namespace(namespace) => &namespace.members,
+        _ => &[],
+    }
+}
+
+fn dictionary_members(definition: &Definition) -> &[DictionaryMember] {
+    match definition {
+        Definition::Dictionary(dictionary) => &dictionary.members,
+        _ => &[],
+    }
+}
+
+fn check_includes(
+    symbols: &SymbolTable,
+    definition: &Definition,
+    includes: &Includes,
+    errors: &mut Vec<Diagnostic>,
+) {
+    let kind = |identifier| symbols.get(identifier).map(|symbol| symbol.kind);
+
+    if kind(&includes.interface) != Some(SymbolKind::Interface) {
+        errors.push(error(
+            Rule::InvalidIncludes,
+            definition,
+            format!("`{}` is not an interface", includes.interface),
+        ));
+    }
+    if kind(&includes.mixin) != Some(SymbolKind::InterfaceMixin) {
+        errors.push(error(
+            Rule::InvalidIncludes,
+            definition,
+            format!("`{}` is not an interface mixin", includes.mixin),
+        ));
+    }
+}
+
+fn error(rule: Rule, definition: &Definition, message: String) -> Diagnostic {
+    Diagnostic {
+        rule,
+        severity: Severity::Error,
+        message,
+        // Includes statements are reported on the interface that includes the mixin.
+        definition: match definition {
+            Definition::Includes(includes) => Some(includes.interface.clone()),
+            definition => definition.get_identifier().map(str::to_string),
+        },
+        member: None,
+        span: definition.get_span().cloned(),
+    }
+}
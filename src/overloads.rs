@@ -0,0 +1,167 @@
+use crate::{Argument, Constructor, Member, Operation, Span, SymbolTable, Type};
+
+/// An operation or constructor of an [`OverloadSet`].
+#[derive(Debug, Clone, Copy)]
+pub enum Callable<'a> {
+    Operation(&'a Operation),
+    Constructor(&'a Constructor),
+}
+
+/// How an argument of an [`OverloadEntry`] may be passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Optionality {
+    Required,
+    Optional,
+    Variadic,
+}
+
+/// One way of calling an operation or constructor with a certain number of arguments.
+#[derive(Debug, Clone)]
+pub struct OverloadEntry<'a> {
+    pub callable: Callable<'a>,
+    /// Types of the arguments, one for each argument.
+    pub types: Vec<&'a Type>,
+    pub optionality: Vec<Optionality>,
+}
+
+/// The effective overload set of operations with the same identifier, or of the constructors of
+/// an interface, as defined in: <https://webidl.spec.whatwg.org/#dfn-effective-overload-set>
+#[derive(Debug, Clone, Default)]
+pub struct OverloadSet<'a> {
+    pub entries: Vec<OverloadEntry<'a>>,
+}
+
+/* Functionality implementations */
+
+impl<'a> Callable<'a> {
+    pub fn arguments(&self) -> &'a [Argument] {
+        match self {
+            Callable::Operation(operation) => &operation.arguments,
+            Callable::Constructor(constructor) => &constructor.arguments,
+        }
+    }
+
+    pub fn span(&self) -> Option<&'a Span> {
+        match self {
+            Callable::Operation(operation) => operation.span.as_ref(),
+            Callable::Constructor(constructor) => constructor.span.as_ref(),
+        }
+    }
+}
+
+impl<'a> OverloadSet<'a> {
+    /// Computes the effective overload set of `callables` for a call with `argument_count`
+    /// arguments. Entries for variadic arguments are added up to `argument_count` arguments, or up
+    /// to the length of the longest argument list, if that's longer.
+    pub fn new(callables: impl IntoIterator<Item = Callable<'a>>, argument_count: usize) -> Self {
+        let callables: Vec<_> = callables.into_iter().collect();
+        let max_arguments = callables
+            .iter()
+            .map(|callable| callable.arguments().len())
+            .max()
+            .unwrap_or(0)
+            .max(argument_count);
+
+        let mut entries = vec![];
+        for callable in callables {
+            let arguments = callable.arguments();
+            let types: Vec<_> = arguments.iter().map(|argument| &argument.r#type).collect();
+            let optionality: Vec<_> = arguments
+                .iter()
+                .map(|argument| match argument {
+                    Argument { variadic: true, .. } => Optionality::Variadic,
+                    Argument { optional: true, .. } => Optionality::Optional,
+                    _ => Optionality::Required,
+                })
+                .collect();
+            let entry = |len: usize| OverloadEntry {
+                callable,
+                types: types[..len].to_vec(),
+                optionality: optionality[..len].to_vec(),
+            };
+
+            entries.push(entry(arguments.len()));
+
+            // The variadic argument is repeated as often as needed.
+            if let Some(last) = arguments.last().filter(|argument| argument.variadic) {
+                for len in arguments.len() + 1..=max_arguments {
+                    let mut entry = entry(arguments.len());
+                    entry.types.resize(len, &last.r#type);
+                    entry.optionality.resize(len, Optionality::Variadic);
+                    entries.push(entry);
+                }
+            }
+
+            // Trailing optional and variadic arguments may be left out.
+            for len in (0..arguments.len()).rev() {
+                if optionality[len] == Optionality::Required {
+                    break;
+                }
+                entries.push(entry(len));
+            }
+        }
+
+        OverloadSet { entries }
+    }
+
+    /// The effective overload set of the operations with the given identifier.
+    pub fn operations(members: &'a [Member], identifier: &str) -> Self {
+        let operations = members.iter().filter_map(|member| match member {
+            Member::Operation(operation) if operation.identifier == identifier => {
+                Some(Callable::Operation(operation))
+            }
+            _ => None,
+        });
+
+        OverloadSet::new(operations, 0)
+    }
+
+    /// The effective overload set of the constructors.
+    pub fn constructors(members: &'a [Member]) -> Self {
+        let constructors = members.iter().filter_map(|member| match member {
+            Member::Constructor(constructor) => Some(Callable::Constructor(constructor)),
+            _ => None,
+        });
+
+        OverloadSet::new(constructors, 0)
+    }
+
+    /// The entries that take exactly `len` arguments.
+    pub fn entries_with_len(&self, len: usize) -> impl Iterator<Item = &OverloadEntry<'a>> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.types.len() == len)
+    }
+
+    /// The numbers of arguments the entries take, in ascending order and without duplicates.
+    pub fn lens(&self) -> Vec<usize> {
+        let mut lens: Vec<_> = self.entries.iter().map(|entry| entry.types.len()).collect();
+        lens.sort_unstable();
+        lens.dedup();
+        lens
+    }
+
+    /// The distinguishing argument index of the entries that take `len` arguments, as defined in:
+    /// <https://webidl.spec.whatwg.org/#dfn-distinguishing-argument-index>
+    ///
+    /// That's the lowest index at which the types of all of them are distinguishable from each
+    /// other. `None` if there is no such index, or fewer than two entries take `len` arguments.
+    pub fn distinguishing_argument_index(
+        &self,
+        len: usize,
+        symbols: &SymbolTable,
+    ) -> Option<usize> {
+        let entries: Vec<_> = self.entries_with_len(len).collect();
+        if entries.len() < 2 {
+            return None;
+        }
+
+        (0..len).find(|&index| {
+            entries.iter().enumerate().all(|(i, a)| {
+                entries[i + 1..]
+                    .iter()
+                    .all(|b| a.types[index].is_distinguishable_from(b.types[index], symbols))
+            })
+        })
+    }
+}
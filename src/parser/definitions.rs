@@ -1,21 +1,18 @@
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_until},
-    character::complete::{char, digit1, hex_digit1},
-    combinator::{map, map_res, not, opt, peek, value},
-    multi::{many0, separated_list0},
-    number::complete::float,
-    sequence::{delimited, preceded, separated_pair, terminated, tuple},
-    IResult,
+    character::complete::char,
+    combinator::{map, opt, peek, value},
+    sequence::{preceded, separated_pair, terminated, tuple},
 };
 
 use crate::{
-    parser, Argument, CallbackFunction, CallbackInterface, DefaultValue, Definition, Dictionary,
+    parser::{self, Context, IResult, Input},
+    Argument, CallbackFunction, CallbackInterface, DefaultValue, Definition, Dictionary,
     DictionaryMember, Enumeration, ExtAttrValue, ExtendedAttribute, Includes, Interface,
     InterfaceMixin, Member, NamedArgumentList, Namespace, Type, Typedef,
 };
 
-fn parse_optional_inheritance(input: &str) -> IResult<&str, Option<String>> {
+fn parse_optional_inheritance(input: Input) -> IResult<Option<String>> {
     opt(preceded(
         tuple((
             parser::multispace_or_comment0,
@@ -26,11 +23,14 @@ fn parse_optional_inheritance(input: &str) -> IResult<&str, Option<String>> {
     ))(input)
 }
 
-fn parse_identifier_for_definition<'a>(input: &'a str, name: &str) -> IResult<&'a str, String> {
+fn parse_identifier_for_definition<'a>(
+    input: Input<'a>,
+    keyword: &'static [&'static str],
+) -> IResult<'a, String> {
     preceded(
         tuple((
             parser::multispace_or_comment0,
-            tag(name),
+            parser::parse_words(keyword),
             parser::multispace_or_comment1,
         )),
         parser::parse_identifier,
@@ -38,8 +38,8 @@ fn parse_identifier_for_definition<'a>(input: &'a str, name: &str) -> IResult<&'
 }
 
 impl Definition {
-    pub fn parse(input: &str) -> IResult<&str, Definition> {
-        terminated(
+    pub(crate) fn parse(input: Input) -> IResult<Definition> {
+        let (input, mut definition) = terminated(
             alt((
                 map(Interface::parse, Definition::Interface),
                 map(InterfaceMixin::parse, Definition::InterfaceMixin),
@@ -52,17 +52,29 @@ impl Definition {
                 map(Typedef::parse, Definition::Typedef),
             )),
             tuple((parser::multispace_or_comment0, char(';'))),
-        )(input)
+        )(input)?;
+
+        // The span also covers the terminating `;`.
+        if let Some(span) = definition.get_span_mut() {
+            span.end = input.location_offset();
+        }
+
+        Ok((input, definition))
     }
 }
 
 impl Interface {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Interface> {
+    pub(crate) fn parse(input: Input) -> IResult<Interface> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, partial) = parser::parse_is_some_attribute(input, "partial")?;
-        let (input, identifier) = parse_identifier_for_definition(input, "interface")?;
+        let (input, identifier) = parse_identifier_for_definition(input, &["interface"])?;
         let (input, inheritance) = parse_optional_inheritance(input)?;
-        let (input, members) = Member::parse_multi0(input)?;
+        let (input, members) = parser::parse_body(
+            Context::Definition("interface", identifier.clone()),
+            Member::parse,
+        )(input)?;
 
         Ok((
             input,
@@ -72,17 +84,23 @@ impl Interface {
                 identifier,
                 inheritance,
                 members,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl InterfaceMixin {
-    pub(crate) fn parse(input: &str) -> IResult<&str, InterfaceMixin> {
+    pub(crate) fn parse(input: Input) -> IResult<InterfaceMixin> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, partial) = parser::parse_is_some_attribute(input, "partial")?;
-        let (input, identifier) = parse_identifier_for_definition(input, "interface mixin")?;
-        let (input, members) = Member::parse_multi0(input)?;
+        let (input, identifier) = parse_identifier_for_definition(input, &["interface", "mixin"])?;
+        let (input, members) = parser::parse_body(
+            Context::Definition("interface mixin", identifier.clone()),
+            Member::parse,
+        )(input)?;
 
         Ok((
             input,
@@ -91,13 +109,16 @@ impl InterfaceMixin {
                 partial,
                 identifier,
                 members,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl Includes {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Includes> {
+    pub(crate) fn parse(input: Input) -> IResult<Includes> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, (interface, mixin)) = preceded(
             parser::multispace_or_comment0,
@@ -105,7 +126,7 @@ impl Includes {
                 parser::parse_identifier,
                 tuple((
                     parser::multispace_or_comment1,
-                    tag("includes"),
+                    parser::tag("includes"),
                     parser::multispace_or_comment1,
                 )),
                 parser::parse_identifier,
@@ -118,16 +139,23 @@ impl Includes {
                 ext_attrs,
                 interface,
                 mixin,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl CallbackInterface {
-    pub(crate) fn parse(input: &str) -> IResult<&str, CallbackInterface> {
+    pub(crate) fn parse(input: Input) -> IResult<CallbackInterface> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
-        let (input, identifier) = parse_identifier_for_definition(input, "callback interface")?;
-        let (input, members) = Member::parse_multi0(input)?;
+        let (input, identifier) =
+            parse_identifier_for_definition(input, &["callback", "interface"])?;
+        let (input, members) = parser::parse_body(
+            Context::Definition("callback interface", identifier.clone()),
+            Member::parse,
+        )(input)?;
 
         Ok((
             input,
@@ -135,17 +163,23 @@ impl CallbackInterface {
                 ext_attrs,
                 identifier,
                 members,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl Namespace {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Namespace> {
+    pub(crate) fn parse(input: Input) -> IResult<Namespace> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, partial) = parser::parse_is_some_attribute(input, "partial")?;
-        let (input, identifier) = parse_identifier_for_definition(input, "namespace")?;
-        let (input, members) = Member::parse_multi0(input)?;
+        let (input, identifier) = parse_identifier_for_definition(input, &["namespace"])?;
+        let (input, members) = parser::parse_body(
+            Context::Definition("namespace", identifier.clone()),
+            Member::parse,
+        )(input)?;
 
         Ok((
             input,
@@ -154,18 +188,24 @@ impl Namespace {
                 partial,
                 identifier,
                 members,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl Dictionary {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Dictionary> {
+    pub(crate) fn parse(input: Input) -> IResult<Dictionary> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, partial) = parser::parse_is_some_attribute(input, "partial")?;
-        let (input, identifier) = parse_identifier_for_definition(input, "dictionary")?;
+        let (input, identifier) = parse_identifier_for_definition(input, &["dictionary"])?;
         let (input, inheritance) = parse_optional_inheritance(input)?;
-        let (input, members) = DictionaryMember::parse_multi0(input)?;
+        let (input, members) = parser::parse_body(
+            Context::Definition("dictionary", identifier.clone()),
+            DictionaryMember::parse,
+        )(input)?;
 
         Ok((
             input,
@@ -175,31 +215,27 @@ impl Dictionary {
                 identifier,
                 inheritance,
                 members,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl Enumeration {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Enumeration> {
+    pub(crate) fn parse(input: Input) -> IResult<Enumeration> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
-        let (input, identifier) = parse_identifier_for_definition(input, "enum")?;
-        let (input, values) = delimited(
-            tuple((parser::multispace_or_comment0, char('{'))),
-            separated_list0(
-                char(','),
-                delimited(
-                    parser::multispace_or_comment0,
-                    parser::parse_quoted_string,
-                    parser::multispace_or_comment0,
-                ),
+        let (input, identifier) = parse_identifier_for_definition(input, &["enum"])?;
+        let (input, values) = parser::context(
+            Context::Definition("enum", identifier.clone()),
+            // The last value may have a comma at the end.
+            parser::parse_list(
+                '{',
+                '}',
+                true,
+                preceded(parser::multispace_or_comment0, parser::parse_string),
             ),
-            tuple((
-                parser::multispace_or_comment0,
-                // This is just in case the last value has a comma at the end.
-                opt(tuple((char(','), parser::multispace_or_comment0))),
-                char('}'),
-            )),
         )(input)?;
 
         Ok((
@@ -208,15 +244,18 @@ impl Enumeration {
                 ext_attrs,
                 identifier,
                 values,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl CallbackFunction {
-    pub(crate) fn parse(input: &str) -> IResult<&str, CallbackFunction> {
+    pub(crate) fn parse(input: Input) -> IResult<CallbackFunction> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
-        let (input, identifier) = parse_identifier_for_definition(input, "callback")?;
+        let (input, identifier) = parse_identifier_for_definition(input, &["callback"])?;
         let (input, r#type) = preceded(
             tuple((parser::multispace_or_comment1, char('='))),
             Type::parse,
@@ -230,18 +269,21 @@ impl CallbackFunction {
                 identifier,
                 r#type,
                 arguments,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl Typedef {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Typedef> {
+    pub(crate) fn parse(input: Input) -> IResult<Typedef> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, r#type) = preceded(
             tuple((
                 parser::multispace_or_comment0,
-                tag("typedef"),
+                parser::tag("typedef"),
                 parser::multispace_or_comment1,
             )),
             Type::parse,
@@ -255,13 +297,16 @@ impl Typedef {
                 ext_attrs,
                 r#type,
                 identifier,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl DictionaryMember {
-    pub(crate) fn parse(input: &str) -> IResult<&str, DictionaryMember> {
+    pub(crate) fn parse(input: Input) -> IResult<DictionaryMember> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, required) = parser::parse_is_some_attribute(input, "required")?;
         let (input, r#type) = terminated(Type::parse, parser::multispace_or_comment1)(input)?;
@@ -280,21 +325,16 @@ impl DictionaryMember {
                 r#type,
                 identifier,
                 default,
+                span: parser::span(start, input),
             },
         ))
     }
-
-    pub(crate) fn parse_multi0(input: &str) -> IResult<&str, Vec<DictionaryMember>> {
-        delimited(
-            preceded(parser::multispace_or_comment0, char('{')),
-            many0(Self::parse),
-            preceded(parser::multispace_or_comment0, char('}')),
-        )(input)
-    }
 }
 
 impl ExtendedAttribute {
-    pub(crate) fn parse(input: &str) -> IResult<&str, ExtendedAttribute> {
+    pub(crate) fn parse(input: Input) -> IResult<ExtendedAttribute> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, identifier) =
             preceded(parser::multispace_or_comment0, parser::parse_identifier)(input)?;
         let (input, value) = opt(alt((
@@ -307,55 +347,62 @@ impl ExtendedAttribute {
             map(Argument::parse_multi0, ExtAttrValue::ArgumentList),
         )))(input)?;
 
-        Ok((input, ExtendedAttribute { identifier, value }))
+        Ok((
+            input,
+            ExtendedAttribute {
+                identifier,
+                value,
+                span: parser::span(start, input),
+            },
+        ))
     }
 
-    pub(crate) fn parse_multi0(input: &str) -> IResult<&str, Vec<ExtendedAttribute>> {
-        map(
-            opt(delimited(
-                tuple((parser::multispace_or_comment0, char('['))),
-                separated_list0(char(','), Self::parse),
-                tuple((parser::multispace_or_comment0, char(']'))),
-            )),
-            |o| o.unwrap_or_default(),
-        )(input)
+    pub(crate) fn parse_multi0(input: Input) -> IResult<Vec<ExtendedAttribute>> {
+        // Only try to parse the list if there is an opening bracket, so that errors inside the
+        // list aren't discarded.
+        if peek(tuple((parser::multispace_or_comment0, char('['))))(input).is_err() {
+            return Ok((input, vec![]));
+        }
+
+        parser::parse_list('[', ']', false, Self::parse)(input)
     }
 }
 
 impl ExtAttrValue {
-    pub(crate) fn parse(input: &str) -> IResult<&str, ExtAttrValue> {
+    pub(crate) fn parse(input: Input) -> IResult<ExtAttrValue> {
         preceded(
             parser::multispace_or_comment0,
             alt((
                 map(NamedArgumentList::parse, ExtAttrValue::NamedArgumentList),
+                map(parser::parse_identifier, ExtAttrValue::Identifier),
+                map(parser::parse_string, ExtAttrValue::String),
+                map(
+                    Self::parse_list(parser::parse_identifier),
+                    ExtAttrValue::IdentifierList,
+                ),
                 map(
-                    alt((parser::parse_identifier, parser::parse_quoted_string)),
-                    ExtAttrValue::Identifier,
+                    Self::parse_list(parser::parse_string),
+                    ExtAttrValue::StringList,
                 ),
-                map(Self::parse_identifier_list, ExtAttrValue::IdentifierList),
                 value(ExtAttrValue::Wildcard, char('*')),
             )),
         )(input)
     }
 
-    fn parse_identifier_list(input: &str) -> IResult<&str, Vec<String>> {
-        delimited(
-            tuple((parser::multispace_or_comment0, char('('))),
-            separated_list0(
-                char(','),
-                delimited(
-                    parser::multispace_or_comment0,
-                    alt((parser::parse_identifier, parser::parse_quoted_string)),
-                    parser::multispace_or_comment0,
-                ),
-            ),
-            tuple((parser::multispace_or_comment0, char(')'))),
-        )(input)
+    fn parse_list<'a, O>(
+        item: fn(Input<'a>) -> IResult<'a, O>,
+    ) -> impl FnMut(Input<'a>) -> IResult<'a, Vec<O>> {
+        parser::parse_list(
+            '(',
+            ')',
+            false,
+            preceded(parser::multispace_or_comment0, item),
+        )
     }
 }
 
 impl NamedArgumentList {
-    pub(crate) fn parse(input: &str) -> IResult<&str, NamedArgumentList> {
+    pub(crate) fn parse(input: Input) -> IResult<NamedArgumentList> {
         let (input, identifier) =
             preceded(parser::multispace_or_comment0, parser::parse_identifier)(input)?;
         let (input, arguments) = Argument::parse_multi0(input)?;
@@ -371,7 +418,9 @@ impl NamedArgumentList {
 }
 
 impl Argument {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Argument> {
+    pub(crate) fn parse(input: Input) -> IResult<Argument> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, optional) = parser::parse_is_some_attribute(input, "optional")?;
         let (input, r#type) = Type::parse(input)?;
@@ -392,51 +441,37 @@ impl Argument {
                 variadic,
                 identifier,
                 default,
+                span: parser::span(start, input),
             },
         ))
     }
 
-    pub(crate) fn parse_multi0(input: &str) -> IResult<&str, Vec<Argument>> {
-        delimited(
-            tuple((parser::multispace_or_comment0, char('('))),
-            separated_list0(char(','), Self::parse),
-            tuple((parser::multispace_or_comment0, char(')'))),
-        )(input)
+    pub(crate) fn parse_multi0(input: Input) -> IResult<Vec<Argument>> {
+        let mut index = 0;
+        parser::parse_list('(', ')', false, move |input| {
+            index += 1;
+            parser::context(Context::Argument(index), Self::parse)(input)
+        })(input)
     }
 }
 
 impl DefaultValue {
-    pub(crate) fn parse(input: &str) -> IResult<&str, DefaultValue> {
+    pub(crate) fn parse(input: Input) -> IResult<DefaultValue> {
         preceded(
             parser::multispace_or_comment0,
             alt((
-                map(alt((tag("true"), tag("false"))), |s: &str| {
-                    DefaultValue::Boolean(s.parse::<bool>().unwrap())
-                }),
-                // Integer in hexadecimal format.
-                map(preceded(tag("0x"), hex_digit1), |s: &str| {
-                    DefaultValue::Integer(i64::from_str_radix(s, 16).unwrap())
-                }),
-                map(
-                    // Make sure there is no "." at the end -> float
-                    map_res(terminated(digit1, not(peek(char('.')))), |s: &str| {
-                        s.parse::<i64>()
-                    }),
-                    DefaultValue::Integer,
-                ),
-                // NOTE: Change this? Don't think we need f64 for WebIDL though.
-                map(float, |f| DefaultValue::Decimal(f as f64)),
-                map(
-                    delimited(char('"'), take_until("\""), char('"')),
-                    |s: &str| DefaultValue::String(s.to_string()),
-                ),
-                value(DefaultValue::Null, tag("null")),
-                value(DefaultValue::Infinity, tag("Infinity")),
-                value(DefaultValue::NegativeInfinity, tag("-Infinity")),
-                value(DefaultValue::NaN, tag("NaN")),
-                value(DefaultValue::Undefined, tag("undefined")),
-                value(DefaultValue::Sequence, tag("[]")),
-                value(DefaultValue::Dictionary, tag("{}")),
+                value(DefaultValue::Boolean(true), parser::tag("true")),
+                value(DefaultValue::Boolean(false), parser::tag("false")),
+                value(DefaultValue::Infinity, parser::tag("Infinity")),
+                value(DefaultValue::NegativeInfinity, parser::tag("-Infinity")),
+                value(DefaultValue::NaN, parser::tag("NaN")),
+                map(parser::parse_integer, DefaultValue::Integer),
+                map(parser::parse_decimal, DefaultValue::Decimal),
+                map(parser::parse_string, DefaultValue::String),
+                value(DefaultValue::Null, parser::tag("null")),
+                value(DefaultValue::Undefined, parser::tag("undefined")),
+                value(DefaultValue::Sequence, parser::parse_words(&["[", "]"])),
+                value(DefaultValue::Dictionary, parser::parse_words(&["{", "}"])),
             )),
         )(input)
     }
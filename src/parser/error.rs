@@ -0,0 +1,209 @@
+use std::fmt;
+
+use itertools::join;
+use nom::error::{ErrorKind, FromExternalError, ParseError as NomParseError};
+
+use crate::parser::Input;
+
+/// Error returned by [`parse`](crate::parse) if the input isn't valid Web IDL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What went wrong. Only [`ParseErrorKind::Syntax`] errors have `expected` tokens.
+    pub kind: ParseErrorKind,
+    /// Byte offset of the error in the input.
+    pub offset: usize,
+    /// Line of the error, starting at 1.
+    pub line: usize,
+    /// Column of the error in characters, starting at 1.
+    pub column: usize,
+    /// Tokens that would have been valid at the position of the error.
+    pub expected: Vec<String>,
+    /// What was being parsed when the error occurred, from the outermost to the innermost
+    /// construct, for example the dictionary followed by the index of the member.
+    pub context: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input doesn't match the Web IDL grammar, see `ParseError::expected`.
+    Syntax,
+    /// An integer literal doesn't fit into any Web IDL integer type.
+    IntegerOutOfRange,
+    /// A decimal literal is too large to be represented as a finite number.
+    DecimalOutOfRange,
+    /// Brackets are nested too deeply for the parser to handle.
+    NestingTooDeep,
+}
+
+// Internal error type used by all parsers. On failure, the error that got the furthest into the
+// input wins, so that `alt()` reports the most specific error instead of the last one tried.
+#[derive(Debug)]
+pub(crate) struct Error<'a> {
+    pub(crate) kind: ParseErrorKind,
+    pub(crate) input: Input<'a>,
+    pub(crate) expected: Vec<Expected>,
+    // Innermost context comes first.
+    pub(crate) context: Vec<Context>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Expected {
+    Char(char),
+    Tag(&'static str),
+    Identifier,
+    Integer,
+    Decimal,
+    String,
+    Type,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Context {
+    Definition(&'static str, String),
+    Member(usize),
+    Argument(usize),
+}
+
+/* Functionality implementations */
+
+impl ParseError {
+    pub(crate) fn from_nom(error: nom::Err<Error>) -> Self {
+        match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => ParseError::new(e),
+            // All parsers are complete, so this can't happen.
+            nom::Err::Incomplete(_) => unreachable!(),
+        }
+    }
+
+    pub(crate) fn new(error: Error) -> Self {
+        ParseError {
+            kind: error.kind,
+            offset: error.input.location_offset(),
+            line: error.input.location_line() as usize,
+            column: error.input.get_utf8_column(),
+            expected: error.expected.iter().map(|e| e.to_string()).collect(),
+            context: error.context.iter().rev().map(|c| c.to_string()).collect(),
+        }
+    }
+}
+
+impl<'a> Error<'a> {
+    pub(crate) fn new(input: Input<'a>, expected: Expected) -> Self {
+        Error {
+            kind: ParseErrorKind::Syntax,
+            input,
+            expected: vec![expected],
+            context: vec![],
+        }
+    }
+
+    pub(crate) fn invalid(input: Input<'a>, kind: ParseErrorKind) -> Self {
+        Error {
+            kind,
+            input,
+            expected: vec![],
+            context: vec![],
+        }
+    }
+
+    pub(crate) fn with_context(mut self, context: Context) -> Self {
+        self.context.push(context);
+        self
+    }
+}
+
+/* Trait implementations */
+
+impl<'a> NomParseError<Input<'a>> for Error<'a> {
+    fn from_error_kind(input: Input<'a>, _: ErrorKind) -> Self {
+        Error {
+            kind: ParseErrorKind::Syntax,
+            input,
+            expected: vec![],
+            context: vec![],
+        }
+    }
+
+    fn append(_: Input<'a>, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: Input<'a>, c: char) -> Self {
+        Error::new(input, Expected::Char(c))
+    }
+
+    fn or(mut self, other: Self) -> Self {
+        let (offset, other_offset) = (self.input.location_offset(), other.input.location_offset());
+        if offset > other_offset {
+            return self;
+        }
+        if offset < other_offset || other.kind != ParseErrorKind::Syntax {
+            return other;
+        }
+
+        // Both errors occurred at the same position, so any of the expected tokens would have
+        // been valid there.
+        for expected in other.expected {
+            if !self.expected.contains(&expected) {
+                self.expected.push(expected);
+            }
+        }
+        if self.context.is_empty() {
+            self.context = other.context;
+        }
+
+        self
+    }
+}
+
+impl<'a, E> FromExternalError<Input<'a>, E> for Error<'a> {
+    fn from_external_error(input: Input<'a>, kind: ErrorKind, _: E) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+
+        match self.kind {
+            ParseErrorKind::Syntax if self.expected.is_empty() => write!(f, "unexpected input")?,
+            ParseErrorKind::Syntax => write!(f, "expected {}", join(&self.expected, " or "))?,
+            ParseErrorKind::IntegerOutOfRange => write!(f, "integer out of range")?,
+            ParseErrorKind::DecimalOutOfRange => write!(f, "decimal out of range")?,
+            ParseErrorKind::NestingTooDeep => write!(f, "nesting too deep")?,
+        }
+
+        if !self.context.is_empty() {
+            write!(f, " (in {})", join(&self.context, ", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Char(c) => write!(f, "`{}`", c),
+            Expected::Tag(tag) => write!(f, "`{}`", tag),
+            Expected::Identifier => write!(f, "identifier"),
+            Expected::Integer => write!(f, "integer"),
+            Expected::Decimal => write!(f, "decimal"),
+            Expected::String => write!(f, "string"),
+            Expected::Type => write!(f, "type"),
+        }
+    }
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Context::Definition(kind, identifier) => write!(f, "{} `{}`", kind, identifier),
+            Context::Member(index) => write!(f, "member {}", index),
+            Context::Argument(index) => write!(f, "argument {}", index),
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use nom::Slice;
+
+use crate::{
+    parser::{Error, Expected, Input, ParseError, State},
+    Span,
+};
+
+/// Kind of a [`Token`], as defined in: <https://webidl.spec.whatwg.org/#idl-grammar>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Integer,
+    Decimal,
+    /// Identifiers include keywords, such as `interface` or `-Infinity`.
+    Identifier,
+    String,
+    Whitespace,
+    Comment,
+    /// Any other single character, or `...`.
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    pub span: Span,
+}
+
+/// Iterator over the tokens of Web IDL input, including whitespace and comments. Stops after the
+/// first error, which can only be an unterminated string or comment.
+#[derive(Debug, Clone)]
+pub struct Lexer<'a> {
+    input: &'a str,
+    offset: usize,
+    file: Option<Arc<str>>,
+    failed: bool,
+}
+
+/* Functionality implementations */
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Lexer {
+            input,
+            offset: 0,
+            file: None,
+            failed: false,
+        }
+    }
+
+    /// Like [`Lexer::new`], but the spans of the tokens refer to `file`.
+    pub fn with_file(input: &'a str, file: &str) -> Self {
+        Lexer {
+            file: Some(file.into()),
+            ..Lexer::new(input)
+        }
+    }
+}
+
+// Returns the kind and length of the token at the start of `input`, which must not be empty.
+pub(crate) fn next_token(input: &str) -> Result<(TokenKind, usize), Expected> {
+    let bytes = input.as_bytes();
+
+    if let Some(len) = whitespace_len(input) {
+        return Ok((TokenKind::Whitespace, len));
+    }
+    if input.starts_with("//") {
+        return Ok((TokenKind::Comment, input.find('\n').unwrap_or(input.len())));
+    }
+    if let Some(rest) = input.strip_prefix("/*") {
+        return match rest.find("*/") {
+            Some(len) => Ok((TokenKind::Comment, len + 4)),
+            None => Err(Expected::Tag("*/")),
+        };
+    }
+    if bytes[0] == b'"' {
+        return match input[1..].find('"') {
+            Some(len) => Ok((TokenKind::String, len + 2)),
+            None => Err(Expected::Char('"')),
+        };
+    }
+
+    // The longest match wins, decimals are preferred over integers.
+    let number = match (decimal_len(input), integer_len(input)) {
+        (Some(decimal), Some(integer)) if integer > decimal => Some((TokenKind::Integer, integer)),
+        (Some(decimal), _) => Some((TokenKind::Decimal, decimal)),
+        (None, Some(integer)) => Some((TokenKind::Integer, integer)),
+        (None, None) => None,
+    };
+    let identifier = identifier_len(input).map(|len| (TokenKind::Identifier, len));
+    match (number, identifier) {
+        (Some(number), Some(identifier)) if identifier.1 > number.1 => return Ok(identifier),
+        (Some(number), _) => return Ok(number),
+        (None, Some(identifier)) => return Ok(identifier),
+        (None, None) => {}
+    }
+
+    if input.starts_with("...") {
+        return Ok((TokenKind::Other, 3));
+    }
+    Ok((TokenKind::Other, input.chars().next().unwrap().len_utf8()))
+}
+
+fn count(input: &[u8], f: impl Fn(u8) -> bool) -> usize {
+    input.iter().take_while(|&&c| f(c)).count()
+}
+
+// [\t\n\r ]+
+fn whitespace_len(input: &str) -> Option<usize> {
+    let len = count(input.as_bytes(), |c| {
+        matches!(c, b'\t' | b'\n' | b'\r' | b' ')
+    });
+    (len > 0).then_some(len)
+}
+
+// [_-]?[A-Za-z][0-9A-Z_a-z-]*
+pub(crate) fn identifier_len(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let prefix = usize::from(matches!(bytes.first(), Some(b'_' | b'-')));
+    if !bytes.get(prefix)?.is_ascii_alphabetic() {
+        return None;
+    }
+
+    let rest = &bytes[prefix + 1..];
+    Some(
+        prefix
+            + 1
+            + count(rest, |c| {
+                c.is_ascii_alphanumeric() || c == b'_' || c == b'-'
+            }),
+    )
+}
+
+// -?([1-9][0-9]*|0[Xx][0-9A-Fa-f]+|0[0-7]*)
+pub(crate) fn integer_len(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let sign = usize::from(bytes.first() == Some(&b'-'));
+    let digits = &bytes[sign..];
+
+    let len = match digits {
+        [b'0', b'X' | b'x', rest @ ..] if rest.first().is_some_and(u8::is_ascii_hexdigit) => {
+            2 + count(rest, |c| c.is_ascii_hexdigit())
+        }
+        [b'0', rest @ ..] => 1 + count(rest, |c| matches!(c, b'0'..=b'7')),
+        [b'1'..=b'9', rest @ ..] => 1 + count(rest, |c| c.is_ascii_digit()),
+        _ => return None,
+    };
+    Some(sign + len)
+}
+
+// -?(([0-9]+\.[0-9]*|[0-9]*\.[0-9]+)([Ee][+-]?[0-9]+)?|[0-9]+[Ee][+-]?[0-9]+)
+pub(crate) fn decimal_len(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let sign = usize::from(bytes.first() == Some(&b'-'));
+    let mut len = sign;
+
+    let integer = count(&bytes[len..], |c| c.is_ascii_digit());
+    len += integer;
+    let fraction = if bytes.get(len) == Some(&b'.') {
+        let fraction = count(&bytes[len + 1..], |c| c.is_ascii_digit());
+        if integer == 0 && fraction == 0 {
+            return None;
+        }
+        len += 1 + fraction;
+        true
+    } else {
+        false
+    };
+    if integer == 0 && !fraction {
+        return None;
+    }
+
+    let exponent = match bytes.get(len) {
+        Some(b'E' | b'e') => {
+            let sign = usize::from(matches!(bytes.get(len + 1), Some(b'+' | b'-')));
+            let digits = count(&bytes[len + 1 + sign..], |c| c.is_ascii_digit());
+            (digits > 0).then_some(1 + sign + digits)
+        }
+        _ => None,
+    };
+    match exponent {
+        Some(exponent) => Some(len + exponent),
+        None if fraction => Some(len),
+        None => None,
+    }
+}
+
+// Value of an integer token. Integers may be of any Web IDL integer type, from `long long` down to
+// `unsigned long long`, so anything outside of that range is rejected.
+pub(crate) fn integer_value(text: &str) -> Option<i128> {
+    if integer_len(text) != Some(text.len()) {
+        return None;
+    }
+
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (digits, radix) = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => (hex, 16),
+        // Integers with a leading zero are octal.
+        None if text.len() > 1 && text.starts_with('0') => (&text[1..], 8),
+        None => (text, 10),
+    };
+
+    let value = i128::from(u64::from_str_radix(digits, radix).ok()?);
+    if !negative {
+        Some(value)
+    } else if value <= -i128::from(i64::MIN) {
+        Some(-value)
+    } else {
+        None
+    }
+}
+
+// Value of a decimal token, if it's finite.
+pub(crate) fn decimal_value(text: &str) -> Option<f64> {
+    if decimal_len(text) != Some(text.len()) {
+        return None;
+    }
+
+    text.parse().ok().filter(|value: &f64| value.is_finite())
+}
+
+/* Trait implementations */
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Result<Token<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.input[self.offset..];
+        if rest.is_empty() || self.failed {
+            return None;
+        }
+
+        match next_token(rest) {
+            Ok((kind, len)) => {
+                let start = self.offset;
+                self.offset += len;
+                Some(Ok(Token {
+                    kind,
+                    text: &rest[..len],
+                    span: Span {
+                        file: self.file.clone(),
+                        start,
+                        end: self.offset,
+                    },
+                }))
+            }
+            Err(expected) => {
+                self.failed = true;
+                let state = State::default();
+                // The closing `"` or `*/` is missing at the end of the input.
+                let input = Input::new_extra(self.input, &state).slice(self.input.len()..);
+                Some(Err(ParseError::new(Error::new(input, expected))))
+            }
+        }
+    }
+}
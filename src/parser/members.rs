@@ -1,27 +1,24 @@
 use nom::{
     branch::alt,
-    bytes::complete::tag,
-    character::complete::{char, digit1, hex_digit1},
-    combinator::{map, map_res, not, opt, peek, value},
-    multi::many0,
-    number::complete::float,
+    character::complete::char,
+    combinator::{map, opt, value},
     sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
-    IResult,
 };
 
 use crate::{
-    parser, Argument, AttrSpecial, Attribute, ConstValue, Constant, Constructor, ExtendedAttribute,
+    parser::{self, IResult, Input},
+    Argument, AttrSpecial, Attribute, ConstValue, Constant, Constructor, ExtendedAttribute,
     Iterable, Maplike, Member, OpSpecial, Operation, Setlike, Stringifer, Type,
 };
 
 fn parse_type_and_identifier_for_member<'a>(
-    input: &'a str,
-    name: &str,
-) -> IResult<&'a str, (Type, String)> {
+    input: Input<'a>,
+    name: &'static str,
+) -> IResult<'a, (Type, String)> {
     preceded(
         tuple((
             parser::multispace_or_comment0,
-            tag(name),
+            parser::tag(name),
             parser::multispace_or_comment1,
         )),
         separated_pair(
@@ -33,8 +30,8 @@ fn parse_type_and_identifier_for_member<'a>(
 }
 
 impl Member {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Member> {
-        terminated(
+    pub(crate) fn parse(input: Input) -> IResult<Member> {
+        let (input, mut member) = terminated(
             alt((
                 map(Constant::parse, Member::Constant),
                 map(Attribute::parse, Member::Attribute),
@@ -46,20 +43,21 @@ impl Member {
                 map(Setlike::parse, Member::Setlike),
             )),
             tuple((parser::multispace_or_comment0, char(';'))),
-        )(input)
-    }
+        )(input)?;
 
-    pub(crate) fn parse_multi0(input: &str) -> IResult<&str, Vec<Member>> {
-        delimited(
-            tuple((parser::multispace_or_comment0, char('{'))),
-            many0(Self::parse),
-            tuple((parser::multispace_or_comment0, char('}'))),
-        )(input)
+        // The span also covers the terminating `;`.
+        if let Some(span) = member.get_span_mut() {
+            span.end = input.location_offset();
+        }
+
+        Ok((input, member))
     }
 }
 
 impl Constant {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Constant> {
+    pub(crate) fn parse(input: Input) -> IResult<Constant> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, (r#type, identifier)) = parse_type_and_identifier_for_member(input, "const")?;
         let (input, value) = preceded(
@@ -74,42 +72,33 @@ impl Constant {
                 r#type,
                 identifier,
                 value,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl ConstValue {
-    pub(crate) fn parse(input: &str) -> IResult<&str, ConstValue> {
+    pub(crate) fn parse(input: Input) -> IResult<ConstValue> {
         preceded(
             parser::multispace_or_comment0,
             alt((
-                map(alt((tag("true"), tag("false"))), |s: &str| {
-                    ConstValue::Boolean(s.parse::<bool>().unwrap())
-                }),
-                // Integer in hexadecimal format.
-                map(preceded(tag("0x"), hex_digit1), |s: &str| {
-                    ConstValue::Integer(i64::from_str_radix(s, 16).unwrap())
-                }),
-                map(
-                    // Make sure there is no "." at the end -> float.
-                    map_res(terminated(digit1, not(peek(char('.')))), |s: &str| {
-                        s.parse::<i64>()
-                    }),
-                    ConstValue::Integer,
-                ),
-                // NOTE: Change this? Don't think we need f64 for WebIDL though.
-                map(float, |f| ConstValue::Decimal(f as f64)),
-                value(ConstValue::Infinity, tag("Infinity")),
-                value(ConstValue::NegativeInfinity, tag("-Infinity")),
-                value(ConstValue::NaN, tag("NaN")),
+                value(ConstValue::Boolean(true), parser::tag("true")),
+                value(ConstValue::Boolean(false), parser::tag("false")),
+                value(ConstValue::Infinity, parser::tag("Infinity")),
+                value(ConstValue::NegativeInfinity, parser::tag("-Infinity")),
+                value(ConstValue::NaN, parser::tag("NaN")),
+                map(parser::parse_integer, ConstValue::Integer),
+                map(parser::parse_decimal, ConstValue::Decimal),
             )),
         )(input)
     }
 }
 
 impl Attribute {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Attribute> {
+    pub(crate) fn parse(input: Input) -> IResult<Attribute> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, special) = AttrSpecial::parse(input)?;
         let (input, readonly) = parser::parse_is_some_attribute(input, "readonly")?;
@@ -124,19 +113,20 @@ impl Attribute {
                 special,
                 r#type,
                 identifier,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl AttrSpecial {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Option<AttrSpecial>> {
+    pub(crate) fn parse(input: Input) -> IResult<Option<AttrSpecial>> {
         opt(delimited(
             parser::multispace_or_comment0,
             alt((
-                value(AttrSpecial::Static, tag("static")),
-                value(AttrSpecial::Stringifier, tag("stringifier")),
-                value(AttrSpecial::Inherit, tag("inherit")),
+                value(AttrSpecial::Static, parser::tag("static")),
+                value(AttrSpecial::Stringifier, parser::tag("stringifier")),
+                value(AttrSpecial::Inherit, parser::tag("inherit")),
             )),
             parser::multispace_or_comment1,
         ))(input)
@@ -144,7 +134,9 @@ impl AttrSpecial {
 }
 
 impl Operation {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Operation> {
+    pub(crate) fn parse(input: Input) -> IResult<Operation> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, special) = OpSpecial::parse(input)?;
         // Can't use `parse_member_type()` here since an operation doesn't have a tag and
@@ -172,20 +164,21 @@ impl Operation {
                 r#type,
                 identifier,
                 arguments,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl OpSpecial {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Option<OpSpecial>> {
+    pub(crate) fn parse(input: Input) -> IResult<Option<OpSpecial>> {
         opt(delimited(
             parser::multispace_or_comment0,
             alt((
-                value(OpSpecial::Static, tag("static")),
-                value(OpSpecial::Getter, tag("getter")),
-                value(OpSpecial::Setter, tag("setter")),
-                value(OpSpecial::Deleter, tag("deleter")),
+                value(OpSpecial::Static, parser::tag("static")),
+                value(OpSpecial::Getter, parser::tag("getter")),
+                value(OpSpecial::Setter, parser::tag("setter")),
+                value(OpSpecial::Deleter, parser::tag("deleter")),
             )),
             parser::multispace_or_comment1,
         ))(input)
@@ -193,10 +186,12 @@ impl OpSpecial {
 }
 
 impl Constructor {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Constructor> {
+    pub(crate) fn parse(input: Input) -> IResult<Constructor> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, arguments) = preceded(
-            tuple((parser::multispace_or_comment0, tag("constructor"))),
+            tuple((parser::multispace_or_comment0, parser::tag("constructor"))),
             Argument::parse_multi0,
         )(input)?;
 
@@ -205,28 +200,40 @@ impl Constructor {
             Constructor {
                 ext_attrs,
                 arguments,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl Stringifer {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Stringifer> {
+    pub(crate) fn parse(input: Input) -> IResult<Stringifer> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
-        let (input, _) = tuple((parser::multispace_or_comment0, tag("stringifier")))(input)?;
+        let (input, _) =
+            tuple((parser::multispace_or_comment0, parser::tag("stringifier")))(input)?;
 
-        Ok((input, Stringifer { ext_attrs }))
+        Ok((
+            input,
+            Stringifer {
+                ext_attrs,
+                span: parser::span(start, input),
+            },
+        ))
     }
 }
 
 impl Iterable {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Iterable> {
+    pub(crate) fn parse(input: Input) -> IResult<Iterable> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, r#async) = parser::parse_is_some_attribute(input, "async")?;
         let (input, (first_type, second_type)) = delimited(
             tuple((
                 parser::multispace_or_comment0,
-                tag("iterable"),
+                parser::tag("iterable"),
                 parser::multispace_or_comment0,
                 char('<'),
             )),
@@ -251,6 +258,7 @@ impl Iterable {
                     key_type: Some(first_type),
                     value_type,
                     arguments,
+                    span: parser::span(start, input),
                 },
             ));
         }
@@ -264,19 +272,22 @@ impl Iterable {
                 key_type: None,
                 value_type: first_type,
                 arguments,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl Maplike {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Maplike> {
+    pub(crate) fn parse(input: Input) -> IResult<Maplike> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, readonly) = parser::parse_is_some_attribute(input, "readonly")?;
         let (input, (key_type, value_type)) = delimited(
             tuple((
                 parser::multispace_or_comment0,
-                tag("maplike"),
+                parser::tag("maplike"),
                 parser::multispace_or_comment0,
                 char('<'),
             )),
@@ -295,19 +306,22 @@ impl Maplike {
                 readonly,
                 key_type,
                 value_type,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl Setlike {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Setlike> {
+    pub(crate) fn parse(input: Input) -> IResult<Setlike> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, readonly) = parser::parse_is_some_attribute(input, "readonly")?;
         let (input, r#type) = delimited(
             tuple((
                 parser::multispace_or_comment0,
-                tag("setlike"),
+                parser::tag("setlike"),
                 parser::multispace_or_comment0,
                 char('<'),
             )),
@@ -321,6 +335,7 @@ impl Setlike {
                 ext_attrs,
                 readonly,
                 r#type,
+                span: parser::span(start, input),
             },
         ))
     }
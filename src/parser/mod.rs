@@ -1,6 +1,109 @@
 mod definitions;
+mod error;
+mod lexer;
 mod members;
 mod types;
 mod utils;
 
+pub(crate) use error::*;
+pub use error::{ParseError, ParseErrorKind};
+pub(crate) use lexer::*;
+pub use lexer::{Lexer, Token, TokenKind};
 pub(crate) use utils::*;
+
+use std::{cell::RefCell, sync::Arc};
+
+use nom::{Err, Slice};
+
+use crate::Definition;
+
+// The parsers recurse for every nested bracket, so deeply nested input could overflow the stack.
+const MAX_NESTING: usize = 64;
+
+pub(crate) fn parse_definitions(
+    input: &str,
+    file: Option<Arc<str>>,
+) -> Result<Vec<Definition>, ParseError> {
+    let state = State {
+        file,
+        recovered: None,
+    };
+    let mut input = Input::new_extra(input, &state);
+    let mut definitions = vec![];
+
+    check_nesting(input).map_err(ParseError::new)?;
+    loop {
+        let (rest, _) = multispace_or_comment0(input).map_err(ParseError::from_nom)?;
+        if rest.is_empty() {
+            return Ok(definitions);
+        }
+
+        let (rest, definition) = Definition::parse(rest).map_err(ParseError::from_nom)?;
+        definitions.push(definition);
+        input = rest;
+    }
+}
+
+// On failure, skips to the end of the broken definition or member and continues from there.
+pub(crate) fn parse_definitions_recovering(
+    input: &str,
+    file: Option<Arc<str>>,
+) -> (Vec<Definition>, Vec<ParseError>) {
+    let state = State {
+        file,
+        recovered: Some(RefCell::new(vec![])),
+    };
+    let recovered = state.recovered.as_ref().unwrap();
+    let mut input = Input::new_extra(input, &state);
+    let mut definitions = vec![];
+
+    loop {
+        let (rest, _) = multispace_or_comment0(input).unwrap_or((input, vec![]));
+        if rest.is_empty() {
+            break;
+        }
+
+        let end = skip_definition(rest);
+        let checkpoint = recovered.borrow().len();
+        let result = check_nesting(rest.slice(..end.location_offset() - rest.location_offset()))
+            .map_err(Err::Failure)
+            .and_then(|_| Definition::parse(rest));
+
+        match result {
+            Ok((rest, definition)) => {
+                definitions.push(definition);
+                input = rest;
+            }
+            Err(e) => {
+                // Errors of skipped members are irrelevant if the whole definition is skipped.
+                recovered.borrow_mut().truncate(checkpoint);
+                recovered.borrow_mut().push(ParseError::from_nom(e));
+                input = end;
+            }
+        }
+    }
+
+    (definitions, recovered.take())
+}
+
+fn check_nesting(input: Input) -> Result<(), Error> {
+    let mut depth = 0usize;
+
+    for (i, c) in CodeChars::new(&input) {
+        match c {
+            '(' | '[' | '{' | '<' => {
+                depth += 1;
+                if depth > MAX_NESTING {
+                    return Err(Error::invalid(
+                        input.slice(i..),
+                        ParseErrorKind::NestingTooDeep,
+                    ));
+                }
+            }
+            ')' | ']' | '}' | '>' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
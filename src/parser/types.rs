@@ -1,47 +1,52 @@
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take},
     character::complete::char,
-    combinator::{map, opt, peek, value, verify},
+    combinator::{cut, map, opt, value},
     multi::separated_list1,
-    sequence::{delimited, preceded, separated_pair, terminated, tuple},
-    IResult,
+    sequence::{delimited, preceded, separated_pair, tuple},
 };
 
 use crate::{
-    parser, ExtendedAttribute, FrozenArrayType, ObservableArrayType, PrimitiveType, PromiseType,
+    parser::{self, Expected, IResult, Input},
+    ExtendedAttribute, FrozenArrayType, ObservableArrayType, PrimitiveType, PromiseType,
     RecordType, RecordTypeKey, SequenceType, StandardType, StandardTypeName, Type, UnionType,
 };
 
-fn parse_parameterized_type<'a>(input: &'a str, name: &str) -> IResult<&'a str, Type> {
+// Once the opening `<` is found, the input can't be anything else, hence the `cut()`.
+fn parse_parameterized_type<'a>(input: Input<'a>, name: &'static str) -> IResult<'a, Type> {
     delimited(
         tuple((
             parser::multispace_or_comment0,
-            tag(name),
+            parser::tag(name),
             parser::multispace_or_comment0,
             char('<'),
         )),
-        Type::parse,
-        tuple((parser::multispace_or_comment0, char('>'))),
+        cut(Type::parse),
+        cut(tuple((parser::multispace_or_comment0, char('>')))),
     )(input)
 }
 
 impl Type {
-    pub(crate) fn parse(input: &str) -> IResult<&str, Type> {
-        alt((
-            map(SequenceType::parse, Type::Sequence),
-            map(RecordType::parse, Type::Record),
-            map(PromiseType::parse, Type::Promise),
-            map(FrozenArrayType::parse, Type::FrozenArray),
-            map(ObservableArrayType::parse, Type::ObservableArray),
-            map(UnionType::parse, Type::Union),
-            map(StandardType::parse, Type::Standard),
-        ))(input)
+    pub(crate) fn parse(input: Input) -> IResult<Type> {
+        parser::expect(
+            Expected::Type,
+            alt((
+                map(SequenceType::parse, Type::Sequence),
+                map(RecordType::parse, Type::Record),
+                map(PromiseType::parse, Type::Promise),
+                map(FrozenArrayType::parse, Type::FrozenArray),
+                map(ObservableArrayType::parse, Type::ObservableArray),
+                map(UnionType::parse, Type::Union),
+                map(StandardType::parse, Type::Standard),
+            )),
+        )(input)
     }
 }
 
 impl SequenceType {
-    pub(crate) fn parse(input: &str) -> IResult<&str, SequenceType> {
+    pub(crate) fn parse(input: Input) -> IResult<SequenceType> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, r#type) = parse_parameterized_type(input, "sequence")?;
         let (input, nullable) = map(
             opt(tuple((parser::multispace_or_comment0, char('?')))),
@@ -53,26 +58,29 @@ impl SequenceType {
             SequenceType {
                 r#type: Box::new(r#type),
                 nullable,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl RecordType {
-    pub(crate) fn parse(input: &str) -> IResult<&str, RecordType> {
+    pub(crate) fn parse(input: Input) -> IResult<RecordType> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, (key, value)) = delimited(
             tuple((
                 parser::multispace_or_comment0,
-                tag("record"),
+                parser::tag("record"),
                 parser::multispace_or_comment0,
                 char('<'),
             )),
-            separated_pair(
+            cut(separated_pair(
                 RecordTypeKey::parse,
                 tuple((parser::multispace_or_comment0, char(','))),
                 Type::parse,
-            ),
-            tuple((parser::multispace_or_comment0, char('>'))),
+            )),
+            cut(tuple((parser::multispace_or_comment0, char('>')))),
         )(input)?;
 
         Ok((
@@ -80,26 +88,29 @@ impl RecordType {
             RecordType {
                 key,
                 value: Box::new(value),
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl RecordTypeKey {
-    pub(crate) fn parse(input: &str) -> IResult<&str, RecordTypeKey> {
+    pub(crate) fn parse(input: Input) -> IResult<RecordTypeKey> {
         preceded(
             parser::multispace_or_comment0,
             alt((
-                map(tag("DOMString"), |_| RecordTypeKey::DOMString),
-                map(tag("USVString"), |_| RecordTypeKey::USVString),
-                map(tag("ByteString"), |_| RecordTypeKey::ByteString),
+                map(parser::tag("DOMString"), |_| RecordTypeKey::DOMString),
+                map(parser::tag("USVString"), |_| RecordTypeKey::USVString),
+                map(parser::tag("ByteString"), |_| RecordTypeKey::ByteString),
             )),
         )(input)
     }
 }
 
 impl UnionType {
-    pub(crate) fn parse(input: &str) -> IResult<&str, UnionType> {
+    pub(crate) fn parse(input: Input) -> IResult<UnionType> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, types) = delimited(
             tuple((
@@ -110,7 +121,7 @@ impl UnionType {
             separated_list1(
                 tuple((
                     parser::multispace_or_comment1,
-                    tag("or"),
+                    parser::tag("or"),
                     parser::multispace_or_comment1,
                 )),
                 Type::parse,
@@ -128,13 +139,16 @@ impl UnionType {
                 ext_attrs,
                 types,
                 nullable,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl PromiseType {
-    pub(crate) fn parse(input: &str) -> IResult<&str, PromiseType> {
+    pub(crate) fn parse(input: Input) -> IResult<PromiseType> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, r#type) = parse_parameterized_type(input, "Promise")?;
         let (input, nullable) = map(
             opt(tuple((parser::multispace_or_comment0, char('?')))),
@@ -146,13 +160,16 @@ impl PromiseType {
             PromiseType {
                 r#type: Box::new(r#type),
                 nullable,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl FrozenArrayType {
-    pub(crate) fn parse(input: &str) -> IResult<&str, FrozenArrayType> {
+    pub(crate) fn parse(input: Input) -> IResult<FrozenArrayType> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, r#type) = parse_parameterized_type(input, "FrozenArray")?;
         let (input, nullable) = map(
             opt(tuple((parser::multispace_or_comment0, char('?')))),
@@ -164,13 +181,16 @@ impl FrozenArrayType {
             FrozenArrayType {
                 r#type: Box::new(r#type),
                 nullable,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl ObservableArrayType {
-    pub(crate) fn parse(input: &str) -> IResult<&str, ObservableArrayType> {
+    pub(crate) fn parse(input: Input) -> IResult<ObservableArrayType> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, r#type) = parse_parameterized_type(input, "ObservableArray")?;
         let (input, nullable) = map(
             opt(tuple((parser::multispace_or_comment0, char('?')))),
@@ -182,13 +202,16 @@ impl ObservableArrayType {
             ObservableArrayType {
                 r#type: Box::new(r#type),
                 nullable,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl StandardType {
-    pub(crate) fn parse(input: &str) -> IResult<&str, StandardType> {
+    pub(crate) fn parse(input: Input) -> IResult<StandardType> {
+        let (input, _) = parser::multispace_or_comment0(input)?;
+        let start = input;
         let (input, ext_attrs) = ExtendedAttribute::parse_multi0(input)?;
         let (input, name) = StandardTypeName::parse(input)?;
         let (input, nullable) = map(
@@ -202,13 +225,14 @@ impl StandardType {
                 ext_attrs,
                 name,
                 nullable,
+                span: parser::span(start, input),
             },
         ))
     }
 }
 
 impl StandardTypeName {
-    pub(crate) fn parse(input: &str) -> IResult<&str, StandardTypeName> {
+    pub(crate) fn parse(input: Input) -> IResult<StandardTypeName> {
         preceded(
             parser::multispace_or_comment0,
             alt((
@@ -220,59 +244,75 @@ impl StandardTypeName {
 }
 
 impl PrimitiveType {
-    pub(crate) fn parse(input: &str) -> IResult<&str, PrimitiveType> {
-        terminated(
+    pub(crate) fn parse(input: Input) -> IResult<PrimitiveType> {
+        alt((
             alt((
-                value(PrimitiveType::UnsignedShort, tag("unsigned short")),
-                value(PrimitiveType::UnsignedLongLong, tag("unsigned long long")),
-                value(PrimitiveType::UnsignedLong, tag("unsigned long")),
-                value(PrimitiveType::LongLong, tag("long long")),
-                value(PrimitiveType::UnrestrictedFloat, tag("unrestricted float")),
+                value(
+                    PrimitiveType::UnsignedShort,
+                    parser::parse_words(&["unsigned", "short"]),
+                ),
+                value(
+                    PrimitiveType::UnsignedLongLong,
+                    parser::parse_words(&["unsigned", "long", "long"]),
+                ),
+                value(
+                    PrimitiveType::UnsignedLong,
+                    parser::parse_words(&["unsigned", "long"]),
+                ),
+                value(
+                    PrimitiveType::LongLong,
+                    parser::parse_words(&["long", "long"]),
+                ),
+                value(
+                    PrimitiveType::UnrestrictedFloat,
+                    parser::parse_words(&["unrestricted", "float"]),
+                ),
                 value(
                     PrimitiveType::UnrestrictedDouble,
-                    tag("unrestricted double"),
+                    parser::parse_words(&["unrestricted", "double"]),
                 ),
-                value(PrimitiveType::Any, tag("any")),
-                value(PrimitiveType::Undefined, tag("undefined")),
-                // NOTE: Interpreting "void" as "undefined", see: https://github.com/whatwg/webidl/issues/60
-                value(PrimitiveType::Undefined, tag("void")),
-                value(PrimitiveType::Boolean, tag("boolean")),
-                value(PrimitiveType::Byte, tag("byte")),
-                value(PrimitiveType::Octet, tag("octet")),
-                value(PrimitiveType::Short, tag("short")),
-                value(PrimitiveType::Long, tag("long")),
-                value(PrimitiveType::Float, tag("float")),
-                value(PrimitiveType::Double, tag("double")),
-                value(PrimitiveType::Bigint, tag("bigint")),
-                value(PrimitiveType::DOMString, tag("DOMString")),
-                value(PrimitiveType::ByteString, tag("ByteString")),
-                value(PrimitiveType::USVString, tag("USVString")),
-                // There is a limit of 21 parsers by alt().
-                alt((
-                    value(PrimitiveType::Object, tag("object")),
-                    value(PrimitiveType::Symbol, tag("symbol")),
-                    value(PrimitiveType::ArrayBuffer, tag("ArrayBuffer")),
-                    value(PrimitiveType::Int8Array, tag("Int8Array")),
-                    value(PrimitiveType::Int16Array, tag("Int16Array")),
-                    value(PrimitiveType::Int32Array, tag("Int32Array")),
-                    value(PrimitiveType::Uint8Array, tag("Uint8Array")),
-                    value(PrimitiveType::Uint16Array, tag("Uint16Array")),
-                    value(PrimitiveType::Uint32Array, tag("Uint32Array")),
-                    value(PrimitiveType::Uint8ClampedArray, tag("Uint8ClampedArray")),
-                    value(PrimitiveType::BigInt64Array, tag("BigInt64Array")),
-                    value(PrimitiveType::BigUint64Array, tag("BigUint64Array")),
-                    value(PrimitiveType::Float32Array, tag("Float32Array")),
-                    value(PrimitiveType::Float64Array, tag("Float64Array")),
-                )),
             )),
-            // Make sure there isn't any character following that may be part of the identifier or
-            // type.
-            // Examples:
-            // * `long longMember` - "long" is the actual type and "longMember" the identifier.
-            // * `DOMStringList foo` - The type is "DOMStringList" and not "DOMString".
-            peek(verify(take(1usize), |s: &str| {
-                !(s.chars().all(|c| c.is_ascii_alphanumeric()) || s == "_" || s == "-")
-            })),
-        )(input)
+            value(PrimitiveType::Any, parser::tag("any")),
+            value(PrimitiveType::Undefined, parser::tag("undefined")),
+            // NOTE: Interpreting "void" as "undefined", see: https://github.com/whatwg/webidl/issues/60
+            value(PrimitiveType::Undefined, parser::tag("void")),
+            value(PrimitiveType::Boolean, parser::tag("boolean")),
+            value(PrimitiveType::Byte, parser::tag("byte")),
+            value(PrimitiveType::Octet, parser::tag("octet")),
+            value(PrimitiveType::Short, parser::tag("short")),
+            value(PrimitiveType::Long, parser::tag("long")),
+            value(PrimitiveType::Float, parser::tag("float")),
+            value(PrimitiveType::Double, parser::tag("double")),
+            value(PrimitiveType::Bigint, parser::tag("bigint")),
+            value(PrimitiveType::DOMString, parser::tag("DOMString")),
+            value(PrimitiveType::ByteString, parser::tag("ByteString")),
+            value(PrimitiveType::USVString, parser::tag("USVString")),
+            value(PrimitiveType::Object, parser::tag("object")),
+            value(PrimitiveType::Symbol, parser::tag("symbol")),
+            // There is a limit of 21 parsers by alt().
+            alt((
+                value(PrimitiveType::ArrayBuffer, parser::tag("ArrayBuffer")),
+                value(
+                    PrimitiveType::SharedArrayBuffer,
+                    parser::tag("SharedArrayBuffer"),
+                ),
+                value(PrimitiveType::DataView, parser::tag("DataView")),
+                value(PrimitiveType::Int8Array, parser::tag("Int8Array")),
+                value(PrimitiveType::Int16Array, parser::tag("Int16Array")),
+                value(PrimitiveType::Int32Array, parser::tag("Int32Array")),
+                value(PrimitiveType::Uint8Array, parser::tag("Uint8Array")),
+                value(PrimitiveType::Uint16Array, parser::tag("Uint16Array")),
+                value(PrimitiveType::Uint32Array, parser::tag("Uint32Array")),
+                value(
+                    PrimitiveType::Uint8ClampedArray,
+                    parser::tag("Uint8ClampedArray"),
+                ),
+                value(PrimitiveType::BigInt64Array, parser::tag("BigInt64Array")),
+                value(PrimitiveType::BigUint64Array, parser::tag("BigUint64Array")),
+                value(PrimitiveType::Float16Array, parser::tag("Float16Array")),
+                value(PrimitiveType::Float32Array, parser::tag("Float32Array")),
+                value(PrimitiveType::Float64Array, parser::tag("Float64Array")),
+            )),
+        ))(input)
     }
 }
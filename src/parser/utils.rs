@@ -1,55 +1,219 @@
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_until, take_while, take_while_m_n},
-    character::complete::multispace1,
-    combinator::{map, opt, recognize, success},
+    bytes::complete::{take_till, take_until, take_while, take_while_m_n},
+    character::complete::{char, multispace1},
+    combinator::{map, opt, recognize},
+    error::ParseError as _,
     multi::{many0, many1},
-    sequence::{delimited, tuple},
-    IResult,
+    sequence::{delimited, pair, preceded, tuple},
+    Err,
 };
+use std::{cell::RefCell, iter::Peekable, str::CharIndices, sync::Arc};
+
+use nom::Slice;
+use nom_locate::LocatedSpan;
+
+use crate::{
+    parser::{
+        decimal_value, identifier_len, integer_value, next_token, Context, Error, Expected,
+        ParseError, ParseErrorKind,
+    },
+    DecimalLiteral, IntegerLiteral, Span, StringLiteral, TokenKind,
+};
+
+// State shared by all parsers during a single run.
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    pub(crate) file: Option<Arc<str>>,
+    // If set, members that fail to parse are skipped and their errors collected here instead of
+    // failing the whole definition.
+    pub(crate) recovered: Option<RefCell<Vec<ParseError>>>,
+}
+
+pub(crate) type Input<'a> = LocatedSpan<&'a str, &'a State>;
+pub(crate) type IResult<'a, O> = nom::IResult<Input<'a>, O, Error<'a>>;
+
+// Span of the input consumed between `start` and `end`.
+pub(crate) fn span(start: Input, end: Input) -> Option<Span> {
+    Some(Span {
+        file: start.extra.file.clone(),
+        start: start.location_offset(),
+        end: end.location_offset(),
+    })
+}
+
+// Same as `nom::bytes::complete::tag()`, but remembers the tag as expected token on failure.
+// Keywords only match whole identifier tokens, so `interface` doesn't match `interfaces`.
+pub(crate) fn tag<'a>(tag: &'static str) -> impl FnMut(Input<'a>) -> IResult<'a, Input<'a>> {
+    let keyword = identifier_len(tag) == Some(tag.len());
+
+    move |input| {
+        let error = || Err::Error(Error::new(input, Expected::Tag(tag)));
+        if keyword && identifier_len(&input) != Some(tag.len()) {
+            return Err(error());
+        }
+
+        nom::bytes::complete::tag(tag)(input).map_err(|_: Err<Error>| error())
+    }
+}
+
+// Parses the tokens of a multi-word keyword such as `unsigned long long` or `interface mixin`, or
+// of a literal such as `[]`, which may be separated by any whitespace or comments. Since keywords
+// only match whole tokens, `long longMember` is parsed as `long` followed by an identifier.
+pub(crate) fn parse_words<'a>(
+    words: &'static [&'static str],
+) -> impl FnMut(Input<'a>) -> IResult<'a, ()> {
+    move |mut input| {
+        for (i, word) in words.iter().enumerate() {
+            if i > 0 {
+                (input, _) = multispace_or_comment0(input)?;
+            }
+            (input, _) = tag(word)(input)?;
+        }
+
+        Ok((input, ()))
+    }
+}
+
+// If `parser` fails without getting past the start of the input, report `expected` instead of
+// whatever it was looking for internally.
+pub(crate) fn expect<'a, O, F>(
+    expected: Expected,
+    mut parser: F,
+) -> impl FnMut(Input<'a>) -> IResult<'a, O>
+where
+    F: FnMut(Input<'a>) -> IResult<'a, O>,
+{
+    move |input| {
+        parser(input).map_err(|e| {
+            e.map(|e| {
+                let (start, _) = multispace_or_comment0(input).unwrap_or((input, vec![]));
+                if e.input.location_offset() <= start.location_offset() {
+                    Error::new(start, expected.clone())
+                } else {
+                    e
+                }
+            })
+        })
+    }
+}
+
+pub(crate) fn context<'a, O, F>(
+    context: Context,
+    mut parser: F,
+) -> impl FnMut(Input<'a>) -> IResult<'a, O>
+where
+    F: FnMut(Input<'a>) -> IResult<'a, O>,
+{
+    move |input| parser(input).map_err(|e| e.map(|e| e.with_context(context.clone())))
+}
 
 // As definined in: https://webidl.spec.whatwg.org/#idl-grammar
-pub(crate) fn parse_identifier(input: &str) -> IResult<&str, String> {
-    map(
-        recognize(tuple((
-            // [_-]?
-            alt((tag("_"), tag("-"), success(""))),
-            // [A-Za-z]
-            take_while_m_n(1, 1, |s: char| s.is_ascii_alphabetic()),
-            // [0-9A-Z_a-z-]*
-            take_while(|s: char| s.is_ascii_alphanumeric() || s == '_' || s == '-'),
-        ))),
-        |s| s.to_string(),
+pub(crate) fn parse_identifier(input: Input) -> IResult<String> {
+    expect(
+        Expected::Identifier,
+        map(
+            recognize(tuple((
+                // [_-]?
+                opt(alt((tag("_"), tag("-")))),
+                // [A-Za-z]
+                take_while_m_n(1, 1, |s: char| s.is_ascii_alphabetic()),
+                // [0-9A-Z_a-z-]*
+                take_while(|s: char| s.is_ascii_alphanumeric() || s == '_' || s == '-'),
+            ))),
+            |s: Input| s.to_string(),
+        ),
     )(input)
 }
 
-pub(crate) fn multispace_or_comment0(input: &str) -> IResult<&str, Vec<&str>> {
-    many0(alt((
-        multispace1,
-        delimited(tag("//"), take_until("\n"), tag("\n")),
-        delimited(tag("/*"), take_until("*/"), tag("*/")),
-    )))(input)
+// Comments are parsed with nom's `tag()`, since they shouldn't show up as expected tokens.
+fn comment(input: Input) -> IResult<Input> {
+    alt((
+        // The line break is left to `multispace1`, a comment on the last line doesn't have one.
+        preceded(nom::bytes::complete::tag("//"), take_till(|c| c == '\n')),
+        delimited(
+            nom::bytes::complete::tag("/*"),
+            take_until("*/"),
+            nom::bytes::complete::tag("*/"),
+        ),
+    ))(input)
 }
 
-pub(crate) fn multispace_or_comment1(input: &str) -> IResult<&str, Vec<&str>> {
-    many1(alt((
-        multispace1,
-        delimited(tag("//"), take_until("\n"), tag("\n")),
-        delimited(tag("/*"), take_until("*/"), tag("*/")),
-    )))(input)
+pub(crate) fn multispace_or_comment0(input: Input) -> IResult<Vec<Input>> {
+    many0(alt((multispace1, comment)))(input)
 }
 
-pub(crate) fn parse_quoted_string(input: &str) -> IResult<&str, String> {
-    map(
-        delimited(tag("\""), take_until("\""), tag("\"")),
-        |s: &str| s.to_string(),
+pub(crate) fn multispace_or_comment1(input: Input) -> IResult<Vec<Input>> {
+    many1(alt((multispace1, comment)))(input)
+}
+
+pub(crate) fn parse_string(input: Input) -> IResult<StringLiteral> {
+    map(token(TokenKind::String, Expected::String), |s: Input| {
+        StringLiteral(s[1..s.len() - 1].to_string())
+    })(input)
+}
+
+// Like `map_opt()`, but if `f` fails, the input is rejected with an error of the given kind
+// instead of giving other parsers a chance to match.
+pub(crate) fn map_literal<'a, O1, O2, F, G>(
+    kind: ParseErrorKind,
+    mut parser: F,
+    mut f: G,
+) -> impl FnMut(Input<'a>) -> IResult<'a, O2>
+where
+    F: FnMut(Input<'a>) -> IResult<'a, O1>,
+    G: FnMut(O1) -> Option<O2>,
+{
+    move |input| {
+        let (rest, o) = parser(input)?;
+        match f(o) {
+            Some(o) => Ok((rest, o)),
+            None => Err(Err::Failure(Error::invalid(input, kind.clone()))),
+        }
+    }
+}
+
+// Matches a whole token of the given kind, as determined by the lexer.
+pub(crate) fn token<'a>(
+    kind: TokenKind,
+    expected: Expected,
+) -> impl FnMut(Input<'a>) -> IResult<'a, Input<'a>> {
+    move |input: Input<'a>| match (!input.is_empty()).then(|| next_token(&input)) {
+        Some(Ok((found, len))) if found == kind => Ok((input.slice(len..), input.slice(..len))),
+        _ => Err(Err::Error(Error::new(input, expected.clone()))),
+    }
+}
+
+pub(crate) fn parse_integer(input: Input) -> IResult<IntegerLiteral> {
+    map_literal(
+        ParseErrorKind::IntegerOutOfRange,
+        token(TokenKind::Integer, Expected::Integer),
+        |s: Input| {
+            Some(IntegerLiteral {
+                value: integer_value(&s)?,
+                raw: Some(s.to_string()),
+            })
+        },
+    )(input)
+}
+
+pub(crate) fn parse_decimal(input: Input) -> IResult<DecimalLiteral> {
+    map_literal(
+        ParseErrorKind::DecimalOutOfRange,
+        token(TokenKind::Decimal, Expected::Decimal),
+        |s: Input| {
+            Some(DecimalLiteral {
+                value: decimal_value(&s)?,
+                raw: Some(s.to_string()),
+            })
+        },
     )(input)
 }
 
 pub(crate) fn parse_is_some_attribute<'a>(
-    input: &'a str,
-    attribute: &str,
-) -> IResult<&'a str, bool> {
+    input: Input<'a>,
+    attribute: &'static str,
+) -> IResult<'a, bool> {
     map(
         opt(tuple((
             multispace_or_comment0,
@@ -59,3 +223,172 @@ pub(crate) fn parse_is_some_attribute<'a>(
         |o| o.is_some(),
     )(input)
 }
+
+// Parses a comma-separated list of `item`s enclosed by `open` and `close`. If an item fails to
+// parse, its error is kept around and reported instead of the missing `close` if it got further
+// into the input.
+pub(crate) fn parse_list<'a, O, F>(
+    open: char,
+    close: char,
+    trailing_comma: bool,
+    mut item: F,
+) -> impl FnMut(Input<'a>) -> IResult<'a, Vec<O>>
+where
+    F: FnMut(Input<'a>) -> IResult<'a, O>,
+{
+    move |input| {
+        let (mut input, _) = pair(multispace_or_comment0, char(open))(input)?;
+        let mut items = vec![];
+
+        let item_error = match item(input) {
+            Ok((rest, first)) => {
+                items.push(first);
+                input = rest;
+
+                loop {
+                    let (rest, _) = match pair(multispace_or_comment0, char(','))(input) {
+                        Ok(ok) => ok,
+                        Err(Err::Error(e)) => break e,
+                        Err(e) => return Err(e),
+                    };
+
+                    match item(rest) {
+                        Ok((rest, next)) => {
+                            items.push(next);
+                            input = rest;
+                        }
+                        Err(Err::Error(e)) if trailing_comma => {
+                            input = rest;
+                            break e;
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+            Err(Err::Error(e)) => e,
+            Err(e) => return Err(e),
+        };
+
+        match pair(multispace_or_comment0, char(close))(input) {
+            Ok((input, _)) => Ok((input, items)),
+            Err(e) => Err(e.map(|e| e.or(item_error))),
+        }
+    }
+}
+
+// Parses the `item`s enclosed by curly braces of definitions such as interfaces or
+// dictionaries. When recovering, an item that fails to parse is skipped up to the next `;`.
+pub(crate) fn parse_body<'a, O, F>(
+    context: Context,
+    mut item: F,
+) -> impl FnMut(Input<'a>) -> IResult<'a, Vec<O>>
+where
+    F: FnMut(Input<'a>) -> IResult<'a, O>,
+{
+    move |input| {
+        let (mut input, _) = pair(multispace_or_comment0, char('{'))(input)
+            .map_err(|e| e.map(|e| e.with_context(context.clone())))?;
+        let mut items = vec![];
+        // Number of items so far, including skipped ones.
+        let mut index = 0;
+
+        loop {
+            let close_error = match pair(multispace_or_comment0, char('}'))(input) {
+                Ok((input, _)) => return Ok((input, items)),
+                Err(Err::Error(e)) => e,
+                Err(e) => return Err(e),
+            };
+
+            index += 1;
+            let error = match item(input) {
+                Ok((rest, next)) => {
+                    items.push(next);
+                    input = rest;
+                    continue;
+                }
+                Err(e) => e.map(|e| {
+                    close_error
+                        .or(e)
+                        .with_context(Context::Member(index))
+                        .with_context(context.clone())
+                }),
+            };
+
+            let (Some(recovered), Some(rest)) = (&input.extra.recovered, skip_member(input)) else {
+                return Err(error);
+            };
+            if let Err::Error(e) | Err::Failure(e) = error {
+                recovered.borrow_mut().push(ParseError::new(e));
+            }
+            input = rest;
+        }
+    }
+}
+
+// Iterates over the characters of the input that are neither part of a comment nor of a string.
+pub(crate) struct CodeChars<'a> {
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> CodeChars<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        CodeChars {
+            chars: input.char_indices().peekable(),
+        }
+    }
+}
+
+impl Iterator for CodeChars<'_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chars = &mut self.chars;
+
+        loop {
+            let (i, c) = chars.next()?;
+            match c {
+                '"' => {
+                    chars.find(|(_, c)| *c == '"');
+                }
+                '/' if chars.next_if(|(_, c)| *c == '/').is_some() => {
+                    chars.find(|(_, c)| *c == '\n');
+                }
+                '/' if chars.next_if(|(_, c)| *c == '*').is_some() => {
+                    while let Some((_, c)) = chars.next() {
+                        if c == '*' && chars.next_if(|(_, c)| *c == '/').is_some() {
+                            break;
+                        }
+                    }
+                }
+                _ => return Some((i, c)),
+            }
+        }
+    }
+}
+
+// Returns the input after the next `;` that isn't nested in any brackets.
+pub(crate) fn skip_definition(input: Input) -> Input {
+    skip_to_semicolon(input, false).unwrap_or_else(|| input.slice(input.len()..))
+}
+
+// Returns the input after the next `;` that isn't nested in any brackets, or the input starting
+// at the `}` closing the current body, whichever comes first.
+pub(crate) fn skip_member(input: Input) -> Option<Input> {
+    skip_to_semicolon(input, true)
+}
+
+fn skip_to_semicolon(input: Input, in_body: bool) -> Option<Input> {
+    let mut depth = 0usize;
+
+    for (i, c) in CodeChars::new(&input) {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            '}' if depth == 0 && in_body => return Some(input.slice(i..)),
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ';' if depth == 0 => return Some(input.slice(i + 1..)),
+            _ => {}
+        }
+    }
+
+    None
+}
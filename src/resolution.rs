@@ -0,0 +1,297 @@
+use std::{fmt, ptr};
+
+use crate::{
+    distinguishability::{has_dictionary, includes_nullable, inherits, Category},
+    Callable, Optionality, OverloadEntry, OverloadSet, PrimitiveType, StandardTypeName,
+    SymbolTable, Type,
+};
+
+/// Abstract JavaScript value, passed as an argument to [`OverloadSet::resolve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsValue {
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    BigInt,
+    String,
+    Symbol,
+    /// Platform object implementing the interface with the given identifier, and thereby all the
+    /// interfaces it inherits from.
+    PlatformObject(String),
+    /// Buffer source object, such as an `ArrayBuffer` or a typed array.
+    BufferSource(PrimitiveType),
+    /// Function, or any other object that is callable.
+    Callable,
+    /// Object with a `Symbol.iterator` method, such as an array.
+    Iterable,
+    /// Any other object.
+    Object,
+}
+
+/// The `TypeError` overload resolution throws, when no operation or constructor can be called
+/// with the given arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeError {
+    pub message: String,
+}
+
+/* Functionality implementations */
+
+impl JsValue {
+    pub fn is_object(&self) -> bool {
+        matches!(
+            self,
+            JsValue::PlatformObject(_)
+                | JsValue::BufferSource(_)
+                | JsValue::Callable
+                | JsValue::Iterable
+                | JsValue::Object
+        )
+    }
+}
+
+impl<'a> OverloadSet<'a> {
+    /// Selects the operation or constructor that is called with `arguments`, as defined in:
+    /// <https://webidl.spec.whatwg.org/#es-overloads>
+    ///
+    /// The effective overload set is computed again for the number of arguments, so `self` may be
+    /// computed for any number of arguments. Besides selecting an entry, the arguments are checked
+    /// for whether they can be converted to the types of the selected entry at all. Conversions
+    /// that only fail for some values of a type, such as `NaN` for a `double`, aren't considered.
+    pub fn resolve(
+        &self,
+        arguments: &[JsValue],
+        symbols: &SymbolTable,
+    ) -> Result<Callable<'a>, TypeError> {
+        let mut callables: Vec<Callable> = vec![];
+        for entry in &self.entries {
+            if !callables.iter().any(|c| same_callable(c, &entry.callable)) {
+                callables.push(entry.callable);
+            }
+        }
+        let overload_set = OverloadSet::new(callables, arguments.len());
+
+        let max_arguments = overload_set.lens().last().copied().unwrap_or(0);
+        let len = arguments.len().min(max_arguments);
+        let mut entries: Vec<_> = overload_set.entries_with_len(len).collect();
+        if entries.is_empty() {
+            return Err(TypeError::new(format!(
+                "no overload takes {} arguments",
+                len
+            )));
+        }
+
+        if entries.len() > 1 {
+            let Some(index) = overload_set.distinguishing_argument_index(len, symbols) else {
+                return Err(TypeError::new(format!(
+                    "overloads with {} arguments can't be distinguished",
+                    len
+                )));
+            };
+            entries = select(entries, index, &arguments[index], symbols).ok_or_else(|| {
+                TypeError::new(format!("argument {} doesn't match any overload", index))
+            })?;
+        }
+
+        let entry = entries[0];
+        for (index, argument) in arguments[..len].iter().enumerate() {
+            let missing = *argument == JsValue::Undefined
+                && entry.optionality[index] == Optionality::Optional;
+            if !missing && !converts(argument, entry.types[index], symbols) {
+                return Err(TypeError::new(format!(
+                    "argument {} can't be converted to `{}`",
+                    index, entry.types[index]
+                )));
+            }
+        }
+
+        Ok(entry.callable)
+    }
+}
+
+impl TypeError {
+    fn new(message: String) -> Self {
+        TypeError { message }
+    }
+}
+
+fn same_callable(a: &Callable, b: &Callable) -> bool {
+    match (a, b) {
+        (Callable::Operation(a), Callable::Operation(b)) => ptr::eq(*a, *b),
+        (Callable::Constructor(a), Callable::Constructor(b)) => ptr::eq(*a, *b),
+        _ => false,
+    }
+}
+
+// Whether an entry's type at the distinguishing index matches, by the category of one of its
+// flattened member types.
+type Step<'s> = Box<dyn Fn(&Category) -> bool + 's>;
+
+// Keeps the entries whose type at `index` `argument` matches best. `None` if it matches none.
+fn select<'e, 'a>(
+    entries: Vec<&'e OverloadEntry<'a>>,
+    index: usize,
+    argument: &JsValue,
+    symbols: &SymbolTable,
+) -> Option<Vec<&'e OverloadEntry<'a>>> {
+    let keep = |f: &dyn Fn(&OverloadEntry) -> bool| {
+        let kept: Vec<_> = entries.iter().copied().filter(|entry| f(entry)).collect();
+        (!kept.is_empty()).then_some(kept)
+    };
+
+    if *argument == JsValue::Undefined {
+        if let Some(kept) = keep(&|entry| entry.optionality[index] == Optionality::Optional) {
+            return Some(kept);
+        }
+    }
+    if matches!(argument, JsValue::Undefined | JsValue::Null) {
+        let kept = keep(&|entry| {
+            let r#type = expand(entry.types[index], symbols);
+            includes_nullable(&r#type, symbols)
+                || has_dictionary(&r#type, symbols)
+                || (*argument == JsValue::Undefined
+                    && flatten(&r#type, symbols).iter().any(|r#type| {
+                        matches!(Category::of(r#type, symbols), Some(Category::Undefined))
+                    }))
+        });
+        if kept.is_some() {
+            return kept;
+        }
+    }
+
+    // The kinds of types to look for, from the most to the least specific.
+    let mut steps: Vec<Step> = vec![];
+    match argument {
+        JsValue::PlatformObject(interface) => {
+            steps.push(Box::new(move |category| match category {
+                Category::InterfaceLike(StandardTypeName::Identifier(identifier)) => {
+                    implements(interface, identifier, symbols)
+                }
+                category => matches!(category, Category::Object),
+            }))
+        }
+        JsValue::BufferSource(buffer) => steps.push(Box::new(move |category| match category {
+            Category::InterfaceLike(StandardTypeName::Primitive(primitive)) => primitive == buffer,
+            category => matches!(category, Category::Object),
+        })),
+        JsValue::Callable => steps.push(Box::new(|category| {
+            matches!(category, Category::CallbackFunction(_) | Category::Object)
+        })),
+        JsValue::Iterable => steps.push(Box::new(|category| {
+            matches!(category, Category::SequenceLike)
+        })),
+        _ => {}
+    }
+    if argument.is_object() {
+        steps.push(Box::new(|category| {
+            matches!(category, Category::DictionaryLike | Category::Object)
+        }));
+    }
+    match argument {
+        JsValue::Boolean => steps.push(Box::new(|category| matches!(category, Category::Boolean))),
+        JsValue::Number => steps.push(Box::new(|category| matches!(category, Category::Numeric))),
+        JsValue::BigInt => steps.push(Box::new(|category| matches!(category, Category::Bigint))),
+        JsValue::Symbol => steps.push(Box::new(|category| matches!(category, Category::Symbol))),
+        _ => {}
+    }
+    // Values of other types are converted to the first of these that an entry has.
+    steps.push(Box::new(|category| matches!(category, Category::String)));
+    steps.push(Box::new(|category| matches!(category, Category::Numeric)));
+    steps.push(Box::new(|category| matches!(category, Category::Boolean)));
+    steps.push(Box::new(|category| matches!(category, Category::Bigint)));
+
+    for step in steps {
+        let kept = keep(&|entry| {
+            flatten(&expand(entry.types[index], symbols), symbols)
+                .iter()
+                .any(|r#type| Category::of(r#type, symbols).is_some_and(|category| step(&category)))
+        });
+        if kept.is_some() {
+            return kept;
+        }
+    }
+
+    keep(&|entry| {
+        flatten(&expand(entry.types[index], symbols), symbols)
+            .iter()
+            .any(is_any)
+    })
+}
+
+// Whether `argument` can be converted to `type` at all.
+fn converts(argument: &JsValue, r#type: &Type, symbols: &SymbolTable) -> bool {
+    let r#type = expand(r#type, symbols);
+    if matches!(argument, JsValue::Undefined | JsValue::Null)
+        && (includes_nullable(&r#type, symbols) || has_dictionary(&r#type, symbols))
+    {
+        return true;
+    }
+
+    flatten(&r#type, symbols).iter().any(|r#type| {
+        if is_any(r#type) {
+            return true;
+        }
+        // Promises accept any value, and types that aren't defined are given the benefit of the
+        // doubt.
+        let Some(category) = Category::of(r#type, symbols) else {
+            return true;
+        };
+
+        match category {
+            Category::Undefined => *argument == JsValue::Undefined,
+            Category::Boolean => true,
+            Category::Numeric => !matches!(argument, JsValue::BigInt | JsValue::Symbol),
+            Category::Bigint => !matches!(
+                argument,
+                JsValue::Undefined | JsValue::Null | JsValue::Number | JsValue::Symbol
+            ),
+            Category::String => *argument != JsValue::Symbol,
+            Category::Object | Category::DictionaryLike => argument.is_object(),
+            Category::Symbol => *argument == JsValue::Symbol,
+            Category::InterfaceLike(StandardTypeName::Identifier(identifier)) => {
+                matches!(argument, JsValue::PlatformObject(interface)
+                    if implements(interface, identifier, symbols))
+            }
+            Category::InterfaceLike(StandardTypeName::Primitive(primitive)) => {
+                matches!(argument, JsValue::BufferSource(buffer) if buffer == primitive)
+            }
+            // Non-objects are treated as `null` by `[LegacyTreatNonObjectAsNull]`.
+            Category::CallbackFunction(legacy) => {
+                *argument == JsValue::Callable || (legacy && !argument.is_object())
+            }
+            Category::SequenceLike => *argument == JsValue::Iterable,
+        }
+    })
+}
+
+fn expand(r#type: &Type, symbols: &SymbolTable) -> Type {
+    r#type.expand(symbols).unwrap_or_else(|_| r#type.clone())
+}
+
+// The flattened member types of a union, or the type itself.
+fn flatten(r#type: &Type, symbols: &SymbolTable) -> Vec<Type> {
+    match r#type {
+        Type::Union(union) => union.flattened_member_types_with(symbols),
+        r#type => vec![r#type.clone()],
+    }
+}
+
+fn is_any(r#type: &Type) -> bool {
+    matches!(r#type, Type::Standard(standard)
+        if standard.name == StandardTypeName::Primitive(PrimitiveType::Any))
+}
+
+fn implements(interface: &str, identifier: &str, symbols: &SymbolTable) -> bool {
+    interface == identifier || inherits(interface, identifier, symbols)
+}
+
+/* Trait implementations */
+
+impl fmt::Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TypeError: {}", self.message)
+    }
+}
+
+impl std::error::Error for TypeError {}
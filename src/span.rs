@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+/// Location of a parsed node in the input. Nodes that weren't created by the parser don't have
+/// one, so nodes that implement `Default` can be built by hand with `..Default::default()` instead
+/// of a span. Spans are left out when comparing or hashing nodes, so the same definitions are equal
+/// no matter where they were parsed from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// Name of the input file, if one was passed to [`parse_with_file`](crate::parse_with_file).
+    pub file: Option<Arc<str>>,
+    /// Byte offset of the first character of the node.
+    pub start: usize,
+    /// Byte offset right after the last character of the node.
+    pub end: usize,
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use crate::{Definition, StandardTypeName, Type};
+
+/// Kind of definition an identifier refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    Interface,
+    InterfaceMixin,
+    CallbackInterface,
+    Namespace,
+    Dictionary,
+    Enumeration,
+    CallbackFunction,
+    Typedef,
+}
+
+/// All definitions with the same identifier.
+#[derive(Debug, Clone)]
+pub struct Symbol<'a> {
+    pub kind: SymbolKind,
+    pub identifier: &'a str,
+    /// The definition that isn't partial, if there is one. If there are multiple, the first one.
+    pub definition: Option<&'a Definition>,
+    /// Partial definitions of the same kind, in the order they appear in.
+    pub partials: Vec<&'a Definition>,
+}
+
+/// Maps the identifiers of a list of definitions to what they define.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolTable<'a> {
+    symbols: HashMap<&'a str, Symbol<'a>>,
+}
+
+/* Functionality implementations */
+
+impl SymbolKind {
+    pub fn of(definition: &Definition) -> Option<SymbolKind> {
+        match definition {
+            Definition::Interface(_) => Some(SymbolKind::Interface),
+            Definition::InterfaceMixin(_) => Some(SymbolKind::InterfaceMixin),
+            Definition::Includes(_) => None,
+            Definition::CallbackInterface(_) => Some(SymbolKind::CallbackInterface),
+            Definition::Namespace(_) => Some(SymbolKind::Namespace),
+            Definition::Dictionary(_) => Some(SymbolKind::Dictionary),
+            Definition::Enumeration(_) => Some(SymbolKind::Enumeration),
+            Definition::CallbackFunction(_) => Some(SymbolKind::CallbackFunction),
+            Definition::Typedef(_) => Some(SymbolKind::Typedef),
+        }
+    }
+
+    /// Whether the identifier can be used as a type. Interface mixins and namespaces can't.
+    pub fn is_type(&self) -> bool {
+        !matches!(self, SymbolKind::InterfaceMixin | SymbolKind::Namespace)
+    }
+}
+
+impl<'a> Symbol<'a> {
+    /// The definition that isn't partial, or the first partial one if there is none.
+    pub fn main_definition(&self) -> &'a Definition {
+        self.definition.unwrap_or_else(|| self.partials[0])
+    }
+
+    /// All definitions, starting with the one that isn't partial.
+    pub fn definitions(&self) -> impl Iterator<Item = &'a Definition> + '_ {
+        self.definition
+            .into_iter()
+            .chain(self.partials.iter().copied())
+    }
+}
+
+impl<'a> SymbolTable<'a> {
+    pub fn new(definitions: &'a [Definition]) -> Self {
+        let mut symbols: HashMap<&str, Symbol> = HashMap::new();
+
+        // Definitions that aren't partial determine the kind of a symbol, so they come first.
+        let (partials, others): (Vec<_>, Vec<_>) = definitions.iter().partition(|d| d.is_partial());
+        for definition in others.into_iter().chain(partials) {
+            let (Some(identifier), Some(kind)) =
+                (definition.get_identifier(), SymbolKind::of(definition))
+            else {
+                continue;
+            };
+
+            let symbol = symbols.entry(identifier).or_insert(Symbol {
+                kind,
+                identifier,
+                definition: None,
+                partials: vec![],
+            });
+            if symbol.kind != kind {
+                continue;
+            }
+            if !definition.is_partial() {
+                symbol.definition.get_or_insert(definition);
+            } else {
+                symbol.partials.push(definition);
+            }
+        }
+
+        SymbolTable { symbols }
+    }
+
+    pub fn get(&self, identifier: &str) -> Option<&Symbol<'a>> {
+        self.symbols.get(identifier)
+    }
+
+    /// The symbol a type refers to by its identifier, such as `Foo` or `Foo?`, if it's defined.
+    /// Primitive types and types built from other types, such as `sequence<Foo>`, don't refer to a
+    /// symbol.
+    pub fn resolve(&self, r#type: &Type) -> Option<&Symbol<'a>> {
+        match r#type {
+            Type::Standard(standard) => match &standard.name {
+                StandardTypeName::Identifier(identifier) => self.get(identifier),
+                StandardTypeName::Primitive(_) => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// All symbols, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &Symbol<'a>> {
+        self.symbols.values()
+    }
+}
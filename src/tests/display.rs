@@ -1,6 +1,6 @@
 use crate::{
-    tests, CallbackFunction, CallbackInterface, Dictionary, Enumeration, Includes, Interface,
-    InterfaceMixin, Namespace, Type, Typedef,
+    tests, CallbackFunction, CallbackInterface, DecimalLiteral, Dictionary, Enumeration, Includes,
+    IntegerLiteral, Interface, InterfaceMixin, Namespace, StringLiteral, Type, Typedef,
 };
 
 #[test]
@@ -11,6 +11,7 @@ fn test_interface_simple() {
         identifier: "Foo".to_string(),
         inheritance: None,
         members: vec![],
+        ..Default::default()
     };
     let expected = tests::load_test_file("interface_simple.idl");
 
@@ -24,6 +25,7 @@ fn test_interface_mixin_simple() {
         partial: false,
         identifier: "Foo".to_string(),
         members: vec![],
+        ..Default::default()
     };
     let expected = tests::load_test_file("interface_mixin_simple.idl");
 
@@ -36,6 +38,7 @@ fn test_includes_simple() {
         ext_attrs: vec![],
         interface: "Foo".to_string(),
         mixin: "Bar".to_string(),
+        ..Default::default()
     };
     let expected = tests::load_test_file("includes_simple.idl");
 
@@ -48,6 +51,7 @@ fn test_callback_interface_simple() {
         ext_attrs: vec![],
         identifier: "Foo".to_string(),
         members: vec![],
+        ..Default::default()
     };
     let expected = tests::load_test_file("callback_interface_simple.idl");
 
@@ -61,6 +65,7 @@ fn test_namespace_simple() {
         partial: false,
         identifier: "Foo".to_string(),
         members: vec![],
+        ..Default::default()
     };
     let expected = tests::load_test_file("namespace_simple.idl");
 
@@ -75,6 +80,7 @@ fn test_dictionary_simple() {
         identifier: "Foo".to_string(),
         inheritance: None,
         members: vec![],
+        ..Default::default()
     };
     let expected = tests::load_test_file("dictionary_simple.idl");
 
@@ -87,6 +93,7 @@ fn test_enumeration_simple() {
         ext_attrs: vec![],
         identifier: "Foo".to_string(),
         values: vec![],
+        ..Default::default()
     };
     let expected = tests::load_test_file("enumeration_simple.idl");
 
@@ -100,6 +107,7 @@ fn test_callback_function_simple() {
         identifier: "Foo".to_string(),
         r#type: Type::from("Bar"),
         arguments: vec![],
+        span: None,
     };
     let expected = tests::load_test_file("callback_function_simple.idl");
 
@@ -112,8 +120,49 @@ fn test_typedef_simple() {
         ext_attrs: vec![],
         r#type: Type::from("Foo"),
         identifier: "Bar".to_string(),
+        span: None,
     };
     let expected = tests::load_test_file("typedef_simple.idl");
 
     assert!(typedef.to_string() == expected);
 }
+
+#[test]
+fn test_literals_round_trip() {
+    let input = tests::load_test_file("literals_round_trip.idl");
+
+    assert!(crate::to_string(&crate::parse(&input).unwrap()) == input);
+}
+
+#[test]
+fn test_literals_without_raw() {
+    assert!(DecimalLiteral::from(0.1).to_string() == "0.1");
+    assert!(DecimalLiteral::from(1.0).to_string() == "1.0");
+    assert!(IntegerLiteral::from(-16).to_string() == "-16");
+    assert!(DecimalLiteral::from(-2.5e-7).to_string() == "-0.00000025");
+    assert!(DecimalLiteral::from(-0.0).to_string() == "-0.0");
+    // Non-finite values use the tokens of constant and default values.
+    assert!(DecimalLiteral::from(f64::NAN).to_string() == "NaN");
+    assert!(DecimalLiteral::from(f64::INFINITY).to_string() == "Infinity");
+    assert!(DecimalLiteral::from(f64::NEG_INFINITY).to_string() == "-Infinity");
+
+    // The original text is only used if it still matches the value.
+    let literal = IntegerLiteral {
+        value: 17,
+        raw: Some("0x10".to_string()),
+    };
+    assert!(literal.to_string() == "17");
+}
+
+#[test]
+fn test_strings_round_trip() {
+    let input = tests::load_test_file("strings.idl");
+
+    assert!(crate::to_string(&crate::parse(&input).unwrap()) == input);
+}
+
+#[test]
+fn test_string_literal_without_quotes() {
+    assert!(format!("{}", StringLiteral::new("café").unwrap()) == "\"café\"");
+    assert!(StringLiteral::new("\"").is_none());
+}
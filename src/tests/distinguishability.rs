@@ -0,0 +1,113 @@
+use crate::{parse, tests, validate, Rule, SymbolTable};
+
+#[test]
+fn test_distinguishable_categories() {
+    let definitions = parse(&tests::load_test_file("distinguishability.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let distinguishable = |a: &str, b: &str| {
+        tests::parse_type(a).is_distinguishable_from(&tests::parse_type(b), &symbols)
+    };
+
+    for (a, b) in [
+        ("boolean", "long"),
+        ("long", "bigint"),
+        ("DOMString", "double"),
+        ("Mode", "long"),
+        ("object", "DOMString"),
+        ("symbol", "object"),
+        ("Node", "Window"),
+        ("Node", "ArrayBuffer"),
+        ("Uint8Array", "ArrayBuffer"),
+        ("Node", "Options"),
+        ("Callback", "sequence<long>"),
+        ("Callback", "Options"),
+        ("Callback", "Listener"),
+        ("undefined", "Node"),
+        ("sequence<long>", "record<DOMString, long>"),
+        ("(long or Node)", "DOMString"),
+        ("long?", "DOMString"),
+    ] {
+        assert!(
+            distinguishable(a, b),
+            "{} and {} aren't distinguishable",
+            a,
+            b
+        );
+        assert!(
+            distinguishable(b, a),
+            "{} and {} aren't distinguishable",
+            b,
+            a
+        );
+    }
+
+    for (a, b) in [
+        ("long", "double"),
+        ("DOMString", "Mode"),
+        ("object", "Node"),
+        ("object", "sequence<long>"),
+        ("Node", "Node"),
+        ("Element", "Node"),
+        ("LegacyCallback", "Options"),
+        ("LegacyCallback", "Listener"),
+        ("undefined", "Options"),
+        ("sequence<long>", "FrozenArray<long>"),
+        ("any", "long"),
+        ("Promise<long>", "DOMString"),
+        ("Unknown", "long"),
+    ] {
+        assert!(
+            !distinguishable(a, b),
+            "{} and {} are distinguishable",
+            a,
+            b
+        );
+        assert!(
+            !distinguishable(b, a),
+            "{} and {} are distinguishable",
+            b,
+            a
+        );
+    }
+}
+
+#[test]
+fn test_distinguishable_nullable_and_unions() {
+    let definitions = parse(&tests::load_test_file("distinguishability.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let distinguishable = |a: &str, b: &str| {
+        tests::parse_type(a).is_distinguishable_from(&tests::parse_type(b), &symbols)
+    };
+
+    // Both include a nullable type, or one of them also accepts `null` as a dictionary.
+    assert!(!distinguishable("long?", "DOMString?"));
+    assert!(!distinguishable("long?", "Options"));
+    assert!(!distinguishable("(long? or Node)", "DOMString?"));
+    assert!(!distinguishable("DOMString?", "(long or Options)"));
+    assert!(!distinguishable("NullableLong", "DOMString?"));
+
+    // Typedefs are expanded, and every member of a union must be distinguishable.
+    assert!(distinguishable("LongOrString", "Node"));
+    assert!(!distinguishable("LongOrString", "Mode"));
+    assert!(distinguishable(
+        "(Node or long)",
+        "(DOMString or sequence<long>)"
+    ));
+    assert!(!distinguishable("(Node or long)", "(Element or DOMString)"));
+}
+
+#[test]
+fn test_validate_callback_and_dictionary_overloads() {
+    let idl = "callback C = undefined ();
+        dictionary D {};
+        interface Foo {
+            undefined f(C c);
+            undefined f(D d);
+        };";
+    assert!(validate(&parse(idl).unwrap()).is_empty());
+
+    let legacy = format!("[LegacyTreatNonObjectAsNull] {}", idl);
+    let diagnostics = validate(&parse(&legacy).unwrap());
+    assert!(diagnostics.len() == 1);
+    assert!(diagnostics[0].rule == Rule::AmbiguousOverload);
+}
@@ -0,0 +1,54 @@
+use crate::{parse_lossless, tests, Attribute, Definition, Member, Type};
+
+#[test]
+fn test_document_unmodified() {
+    let input = tests::load_test_file("lossless.idl");
+    let document = parse_lossless(&input).unwrap();
+
+    assert!(document.to_string() == input);
+}
+
+#[test]
+fn test_document_trivia() {
+    let input = tests::load_test_file("lossless.idl");
+    let document = parse_lossless(&input).unwrap();
+    let Definition::Interface(interface) = &document.definitions[0] else {
+        panic!("Parsed definition is not an interface.");
+    };
+    let span = interface.members[0].get_span().unwrap();
+
+    assert!(document.leading_trivia(span) == Some("\n    // Leading comment of `bar`.\n    "));
+    assert!(document.trailing_trivia(span) == Some(" // Trailing comment of `bar`."));
+}
+
+#[test]
+fn test_document_modified() {
+    let input = tests::load_test_file("lossless.idl");
+    let mut document = parse_lossless(&input).unwrap();
+
+    let Definition::Interface(interface) = &mut document.definitions[0] else {
+        panic!("Parsed definition is not an interface.");
+    };
+    let Member::Operation(operation) = &mut interface.members[1] else {
+        panic!("Parsed member is not an operation.");
+    };
+    operation.identifier = "quux".to_string();
+    interface.members.push(Member::Attribute(Attribute {
+        ext_attrs: vec![],
+        readonly: false,
+        special: None,
+        r#type: Type::from("long"),
+        identifier: "corge".to_string(),
+        span: None,
+    }));
+    document.definitions.remove(1);
+
+    let expected = input
+        .replace("undefined baz(long qux);", "undefined quux(long qux);")
+        .replace(
+            "    // Comment at the end of the body.\n",
+            "\tattribute long corge;\n    // Comment at the end of the body.\n",
+        )
+        .replace("dictionary Baz {\n  long qux = 1;\n};\n\n", "");
+    assert!(document.to_string() == expected);
+}
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use crate::{parse, tests, Definition, Member};
+
+#[test]
+fn test_definitions_eq_ignores_spans() {
+    let input = tests::load_test_file("interface_simple.idl");
+    let definitions = parse(&input).unwrap();
+    let moved = parse(&format!("\n\n  // Comment\n{}", input)).unwrap();
+
+    assert!(definitions == moved);
+    let unique: HashSet<&Definition> = definitions.iter().chain(&moved).collect();
+    assert!(unique.len() == definitions.len());
+}
+
+#[test]
+fn test_definitions_eq_is_structural() {
+    let definitions = parse(
+        "interface Foo {
+            const long a = 16;
+            const long a = 0x10;
+            attribute long b;
+            readonly attribute long b;
+            [Clamp] attribute long c;
+            attribute long c;
+        };",
+    )
+    .unwrap();
+    let Definition::Interface(foo) = &definitions[0] else {
+        panic!("Parsed definition is not an interface.");
+    };
+    let members: Vec<&Member> = foo.members.iter().collect();
+
+    // Literals are compared by their value.
+    assert!(members[0] == members[1]);
+    assert!(members[2] != members[3]);
+    assert!(members[4] != members[5]);
+}
+
+#[test]
+fn test_type_eq_ignoring_annotations() {
+    let types = [
+        "(long or DOMString)",
+        "(DOMString or long)",
+        "(long? or DOMString)?",
+        "record<DOMString, long>",
+        "record<USVString, long>",
+        "sequence<[Clamp] long>",
+        "sequence<long?>?",
+    ]
+    .map(tests::parse_type);
+
+    assert!(types[0] != types[1]);
+    assert!(types[0].eq_ignoring_annotations(&types[1]));
+    assert!(types[0] != types[2]);
+    assert!(types[0].eq_ignoring_annotations(&types[2]));
+    assert!(types[3] != types[4]);
+    assert!(!types[3].eq_ignoring_annotations(&types[4]));
+    assert!(types[5] != types[6]);
+    assert!(types[5].eq_ignoring_annotations(&types[6]));
+
+    // Extended attributes are ignored on their own as well, also on union member types.
+    let annotated = [
+        "[Clamp] long",
+        "long",
+        "([EnforceRange] long or Node)",
+        "(long or Node)",
+    ]
+    .map(tests::parse_type);
+    assert!(annotated[0] != annotated[1]);
+    assert!(annotated[0].eq_ignoring_annotations(&annotated[1]));
+    assert!(annotated[2] != annotated[3]);
+    assert!(annotated[2].eq_ignoring_annotations(&annotated[3]));
+
+    // Member types that appear more than once are counted.
+    let repeated =
+        ["(A or A or B)", "(A or B or B)", "(B or A or [Clamp] A)"].map(tests::parse_type);
+    assert!(!repeated[0].eq_ignoring_annotations(&repeated[1]));
+    assert!(!repeated[1].eq_ignoring_annotations(&repeated[0]));
+    assert!(repeated[0].eq_ignoring_annotations(&repeated[2]));
+}
@@ -0,0 +1,90 @@
+use crate::{
+    fold::{self, Fold},
+    parse, tests, Definition, ExtendedAttribute, Type,
+};
+
+// Replaces references to typedefs with the types they stand for.
+struct ExpandTypedefs<'a>(&'a [Definition]);
+
+impl Fold for ExpandTypedefs<'_> {
+    fn fold_type(&mut self, node: Type) -> Type {
+        let node = node.expand(self.0).unwrap();
+        fold::fold_type(self, node)
+    }
+}
+
+struct StripVendor;
+
+impl Fold for StripVendor {
+    fn fold_ext_attrs(&mut self, node: Vec<ExtendedAttribute>) -> Vec<ExtendedAttribute> {
+        let node = node
+            .into_iter()
+            .filter(|ext_attr| !ext_attr.identifier.starts_with("Moz"))
+            .collect();
+        fold::fold_ext_attrs(self, node)
+    }
+}
+
+struct Identity;
+
+impl Fold for Identity {}
+
+#[test]
+fn test_fold_identity() {
+    let definitions = parse(&tests::load_test_file("fold.idl")).unwrap();
+    let folded: Vec<_> = definitions
+        .iter()
+        .cloned()
+        .map(|definition| Identity.fold_definition(definition))
+        .collect();
+
+    assert!(folded == definitions);
+    // Spans are kept as well.
+    for (a, b) in folded.iter().zip(&definitions) {
+        assert!(a.get_span() == b.get_span());
+    }
+}
+
+#[test]
+fn test_fold_types() {
+    let definitions = parse(&tests::load_test_file("fold.idl")).unwrap();
+    let mut expand = ExpandTypedefs(&definitions);
+    let folded: Vec<_> = definitions
+        .iter()
+        .skip(2)
+        .cloned()
+        .map(|definition| expand.fold_definition(definition))
+        .collect();
+
+    let expected = parse(
+        "[Exposed=Window, MozVendor]
+        interface Foo {
+          [MozVendor] attribute (DOMString or sequence<DOMString>)? name;
+          Promise<record<DOMString, (DOMString or sequence<DOMString>)>> resolve(
+            [MozVendor, Clamp] octet flags, optional sequence<DOMString> names = []);
+        };
+
+        [LegacyNoInterfaceObject=Bar((DOMString or sequence<DOMString>) name)]
+        interface Bar {};",
+    )
+    .unwrap();
+    assert!(folded == expected);
+}
+
+#[test]
+fn test_fold_ext_attrs() {
+    let definitions = parse(&tests::load_test_file("fold.idl")).unwrap();
+    let folded: Vec<_> = definitions
+        .into_iter()
+        .map(|definition| StripVendor.fold_definition(definition))
+        .collect();
+
+    let expected = parse(
+        &tests::load_test_file("fold.idl")
+            .replace(", MozVendor", "")
+            .replace("[MozVendor] ", "")
+            .replace("MozVendor, ", ""),
+    )
+    .unwrap();
+    assert!(folded == expected);
+}
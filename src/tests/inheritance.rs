@@ -0,0 +1,88 @@
+use crate::{merge, parse, tests, validate, InheritanceCycle, Rule, SymbolTable};
+
+#[test]
+fn test_ancestors() {
+    let definitions = parse(&tests::load_test_file("inheritance.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let merged = merge(&definitions);
+
+    let ancestors: Vec<_> = symbols
+        .ancestors("HTMLDivElement")
+        .unwrap()
+        .into_iter()
+        .map(|symbol| symbol.identifier)
+        .collect();
+    assert!(ancestors == ["Element", "Node", "EventTarget"]);
+
+    let ancestors: Vec<_> = merged
+        .dictionary_ancestors("MouseEventInit")
+        .unwrap()
+        .into_iter()
+        .map(|dictionary| dictionary.definition.identifier.as_str())
+        .collect();
+    assert!(ancestors == ["EventInit"]);
+    assert!(merged
+        .interface_ancestors("EventTarget")
+        .unwrap()
+        .is_empty());
+    assert!(symbols.ancestors("Unknown").unwrap().is_empty());
+}
+
+#[test]
+fn test_inherited_members() {
+    let definitions = parse(&tests::load_test_file("inheritance.idl")).unwrap();
+    let merged = merge(&definitions);
+
+    let members: Vec<_> = merged
+        .interface_members("HTMLDivElement")
+        .unwrap()
+        .into_iter()
+        .map(|m| (m.definition, m.member.get_identifier().unwrap()))
+        .collect();
+    assert!(
+        members
+            == [
+                ("EventTarget", "dispatchEvent"),
+                ("Node", "nodeName"),
+                ("Element", "id"),
+                ("Element", "className"),
+                ("HTMLDivElement", "align"),
+            ]
+    );
+
+    let members: Vec<_> = merged
+        .dictionary_members("MouseEventInit")
+        .unwrap()
+        .into_iter()
+        .map(|m| m.member.identifier.as_str())
+        .collect();
+    assert!(members == ["bubbles", "cancelable", "screenX", "screenY"]);
+}
+
+#[test]
+fn test_inheritance_cycle() {
+    let definitions = parse(&tests::load_test_file("inheritance.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let merged = merge(&definitions);
+
+    let cycle = InheritanceCycle {
+        identifiers: vec!["Bar".into(), "Baz".into(), "Bar".into()],
+    };
+    assert!(symbols.ancestors("Foo").unwrap_err() == cycle);
+    assert!(merged.interface_members("Foo").unwrap_err() == cycle);
+    assert!(merged.dictionary_members("Qux").unwrap_err().identifiers == ["Qux", "Qux"]);
+
+    let cycles: Vec<_> = validate(&definitions)
+        .into_iter()
+        .filter(|d| d.rule == Rule::InheritanceCycle)
+        .map(|d| d.message)
+        .collect();
+    assert!(
+        cycles
+            == [
+                "definition inherits from itself: Bar -> Baz -> Bar",
+                "definition inherits from itself: Baz -> Bar -> Baz",
+                "definition inherits from itself: Qux -> Qux",
+            ]
+    );
+}
@@ -0,0 +1,72 @@
+use crate::{Lexer, TokenKind};
+
+fn tokens(input: &str) -> Vec<(TokenKind, &str)> {
+    Lexer::new(input)
+        .map(|token| token.map(|token| (token.kind, token.text)))
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+#[test]
+fn test_lexer_tokens() {
+    use TokenKind::*;
+
+    assert!(
+        tokens("const long x = -0X1f; // foo\n/* bar */ ...")
+            == vec![
+                (Identifier, "const"),
+                (Whitespace, " "),
+                (Identifier, "long"),
+                (Whitespace, " "),
+                (Identifier, "x"),
+                (Whitespace, " "),
+                (Other, "="),
+                (Whitespace, " "),
+                (Integer, "-0X1f"),
+                (Other, ";"),
+                (Whitespace, " "),
+                (Comment, "// foo"),
+                (Whitespace, "\n"),
+                (Comment, "/* bar */"),
+                (Whitespace, " "),
+                (Other, "..."),
+            ]
+    );
+}
+
+#[test]
+fn test_lexer_numbers() {
+    use TokenKind::*;
+
+    assert!(tokens("0755") == vec![(Integer, "0755")]);
+    assert!(tokens("089") == vec![(Integer, "0"), (Integer, "89")]);
+    assert!(tokens("-Infinity") == vec![(Identifier, "-Infinity")]);
+    assert!(tokens("1.") == vec![(Decimal, "1.")]);
+    assert!(tokens(".5e-3") == vec![(Decimal, ".5e-3")]);
+    assert!(tokens("-1E10") == vec![(Decimal, "-1E10")]);
+    assert!(tokens("1e") == vec![(Integer, "1"), (Identifier, "e")]);
+    assert!(tokens("0x") == vec![(Integer, "0"), (Identifier, "x")]);
+}
+
+#[test]
+fn test_lexer_spans() {
+    let tokens: Vec<_> = Lexer::with_file("foo \"bar\"", "foo.idl")
+        .map(Result::unwrap)
+        .collect();
+
+    assert!(tokens[2].kind == TokenKind::String);
+    assert!(tokens[2].span.start == 4 && tokens[2].span.end == 9);
+    assert!(tokens[2].span.file.as_deref() == Some("foo.idl"));
+}
+
+#[test]
+fn test_lexer_unterminated() {
+    let mut lexer = Lexer::new("foo /* bar");
+
+    assert!(lexer.next().unwrap().is_ok());
+    assert!(lexer.next().unwrap().is_ok());
+    let error = lexer.next().unwrap().unwrap_err();
+    assert!(error.offset == 10);
+    assert!(error.expected == vec!["`*/`"]);
+    assert!(lexer.next().is_none());
+}
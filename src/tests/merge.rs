@@ -0,0 +1,86 @@
+use crate::{merge, parse, tests, Member, Rule};
+
+#[test]
+fn test_merge_interface() {
+    let definitions = parse(&tests::load_test_file("merge.idl")).unwrap();
+    let merged = merge(&definitions);
+    let foo = merged.interface("Foo").unwrap();
+    let identifiers: Vec<_> = foo
+        .definition
+        .members
+        .iter()
+        .filter_map(Member::get_identifier)
+        .collect();
+
+    assert!(!foo.definition.partial);
+    assert!(foo.definition.ext_attrs.is_empty());
+    // Members of the mixin are only copied once.
+    assert!(identifiers == ["foo", "bar", "baz", "qux"]);
+
+    let provenance = &foo.provenance;
+    assert!(provenance.len() == 4);
+    assert!(provenance[0].mixin.is_none() && provenance[0].partial.is_none());
+    assert!(provenance[1].mixin.is_none() && provenance[1].partial == Some(0));
+    assert!(provenance[1].ext_attrs[0].identifier == "Exposed");
+    assert!(provenance[2].mixin.as_deref() == Some("Baz") && provenance[2].partial.is_none());
+    assert!(provenance[3].mixin.as_deref() == Some("Baz") && provenance[3].partial == Some(0));
+    assert!(provenance[3].span.as_ref().unwrap().start == definitions[3].get_span().unwrap().start);
+}
+
+#[test]
+fn test_merge_dictionary_and_namespace() {
+    let definitions = parse(&tests::load_test_file("merge.idl")).unwrap();
+    let merged = merge(&definitions);
+    let quux = merged.dictionary("Quux").unwrap();
+    let identifiers: Vec<_> = quux
+        .definition
+        .members
+        .iter()
+        .map(|member| member.identifier.as_str())
+        .collect();
+
+    // Members of the definition that isn't partial come first, even if it's declared later.
+    assert!(identifiers == ["corge", "quux"]);
+    assert!(quux.provenance[1].partial == Some(0));
+    assert!(merged
+        .namespace("Grault")
+        .unwrap()
+        .definition
+        .members
+        .is_empty());
+    assert!(merged.namespace("Garply").is_none());
+    assert!(merged.interfaces.len() == 1);
+}
+
+#[test]
+fn test_merge_errors() {
+    let definitions = parse(&tests::load_test_file("merge.idl")).unwrap();
+    let errors: Vec<_> = merge(&definitions)
+        .errors
+        .into_iter()
+        .map(|e| (e.rule, e.definition.unwrap(), e.message))
+        .collect();
+    let error =
+        |rule, definition: &str, message: &str| (rule, definition.to_string(), message.to_string());
+
+    assert!(
+        errors
+            == vec![
+                error(
+                    Rule::MissingDefinition,
+                    "Garply",
+                    "`Garply` is only defined as partial"
+                ),
+                error(
+                    Rule::DuplicateDefinition,
+                    "Grault",
+                    "`Grault` is not a dictionary"
+                ),
+                error(
+                    Rule::InvalidIncludes,
+                    "Foo",
+                    "`Waldo` is not an interface mixin"
+                ),
+            ]
+    );
+}
@@ -1,5 +1,24 @@
 mod display;
+mod distinguishability;
+mod document;
+mod equality;
+mod fold;
+mod inheritance;
+mod lexer;
+mod merge;
+mod overloads;
 mod parser;
+mod printer;
+mod resolution;
+#[cfg(feature = "serde")]
+mod serialization;
+mod symbols;
+mod typedefs;
+mod unions;
 mod utils;
+mod validation;
+mod visit;
+#[cfg(feature = "webidl2")]
+mod webidl2;
 
 pub(self) use utils::*;
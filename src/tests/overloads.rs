@@ -0,0 +1,102 @@
+use crate::{
+    parse, tests, validate, Callable, Definition, Interface, Optionality, OverloadSet, Rule,
+    SymbolTable,
+};
+
+fn foo(definitions: &[Definition]) -> &Interface {
+    match definitions.last() {
+        Some(Definition::Interface(foo)) => foo,
+        _ => panic!("Parsed definition is not an interface."),
+    }
+}
+
+#[test]
+fn test_effective_overload_set() {
+    let definitions = parse(&tests::load_test_file("overloads.idl")).unwrap();
+    let foo = foo(&definitions);
+
+    let overload_set = OverloadSet::operations(&foo.members, "f");
+    let entries: Vec<_> = overload_set
+        .entries
+        .iter()
+        .map(|entry| {
+            let Callable::Operation(operation) = entry.callable else {
+                panic!("Callable is not an operation.");
+            };
+            (operation.arguments.len(), entry.optionality.clone())
+        })
+        .collect();
+    assert!(
+        entries
+            == [
+                (1, vec![Optionality::Required]),
+                (
+                    3,
+                    vec![
+                        Optionality::Required,
+                        Optionality::Optional,
+                        Optionality::Variadic
+                    ]
+                ),
+                (3, vec![Optionality::Required, Optionality::Optional]),
+                (3, vec![Optionality::Required]),
+            ]
+    );
+    assert!(overload_set.lens() == [1, 2, 3]);
+
+    // Variadic arguments are repeated up to the number of arguments of a call.
+    let Some(Callable::Operation(f)) = OverloadSet::operations(&foo.members, "f")
+        .entries
+        .get(1)
+        .map(|entry| entry.callable)
+    else {
+        panic!("Callable is not an operation.");
+    };
+    let overload_set = OverloadSet::new([Callable::Operation(f)], 5);
+    assert!(overload_set.lens() == [1, 2, 3, 4, 5]);
+    let longest = overload_set.entries_with_len(5).next().unwrap();
+    assert!(longest.types[4] == longest.types[2]);
+    assert!(longest.optionality[4] == Optionality::Variadic);
+
+    let constructors = OverloadSet::constructors(&foo.members);
+    assert!(constructors.lens() == [0, 1, 2]);
+}
+
+#[test]
+fn test_distinguishing_argument_index() {
+    let definitions = parse(&tests::load_test_file("overloads.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let foo = foo(&definitions);
+    let index = |identifier, len| {
+        OverloadSet::operations(&foo.members, identifier)
+            .distinguishing_argument_index(len, &symbols)
+    };
+
+    assert!(index("f", 1) == Some(0));
+    assert!(index("f", 2).is_none());
+    assert!(index("g", 1).is_none());
+    assert!(index("h", 2) == Some(0));
+    assert!(index("i", 0).is_none());
+    assert!(index("j", 2) == Some(1));
+}
+
+#[test]
+fn test_validate_ambiguous_overloads() {
+    let definitions = parse(&tests::load_test_file("overloads.idl")).unwrap();
+    let diagnostics: Vec<_> = validate(&definitions)
+        .into_iter()
+        .map(|d| {
+            assert!(d.rule == Rule::AmbiguousOverload);
+            d.message
+        })
+        .collect();
+
+    assert!(
+        diagnostics
+            == [
+                "overloads of `g` with 1 arguments can't be distinguished",
+                "overloads of `i` with 0 arguments can't be distinguished",
+                "overloads of `j` with 2 arguments differ before the distinguishing argument",
+            ]
+    );
+}
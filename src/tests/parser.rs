@@ -1,4 +1,7 @@
-use crate::{parse, tests, Definition, Type};
+use crate::{
+    parse, parse_recovering, parse_with_file, tests, ConstValue, DefaultValue, Definition,
+    ExtAttrValue, Member, ParseError, ParseErrorKind, PrimitiveType, Type,
+};
 
 #[test]
 fn test_interface_simple() {
@@ -141,3 +144,306 @@ fn test_typedef_simple() {
     assert!(typedef.r#type == Type::from("Foo"));
     assert!(typedef.identifier == "Bar");
 }
+
+#[test]
+fn test_error_position_and_context() {
+    let error = parse(&tests::load_test_file("error_dictionary_member.idl")).unwrap_err();
+
+    assert!(
+        error
+            == ParseError {
+                kind: ParseErrorKind::Syntax,
+                offset: 42,
+                line: 4,
+                column: 3,
+                expected: vec!["`;`".to_string()],
+                context: vec!["dictionary `Foo`".to_string(), "member 2".to_string()],
+            }
+    );
+    assert!(error.to_string() == "line 4, column 3: expected `;` (in dictionary `Foo`, member 2)");
+}
+
+#[test]
+fn test_error_in_argument() {
+    let error = parse("interface Foo {\n\tundefined bar(long a, sequence<> b);\n};").unwrap_err();
+
+    assert!(error.line == 2);
+    assert!(error.column == 33);
+    assert!(error.expected == vec!["type"]);
+    assert!(error.context == vec!["interface `Foo`", "member 1", "argument 2"]);
+}
+
+#[test]
+fn test_error_expected_tokens() {
+    let error = parse("enum Foo { \"bar\", baz };").unwrap_err();
+
+    assert!(error.offset == 18);
+    assert!(error.expected == vec!["`}`", "string"]);
+    assert!(error.context == vec!["enum `Foo`"]);
+
+    let error = parse("[Exposed=Window interface Foo {};").unwrap_err();
+
+    assert!(error.offset == 16);
+    assert!(error.expected == vec!["`]`", "`,`"]);
+    assert!(error.context.is_empty());
+}
+
+#[test]
+fn test_malformed_inputs() {
+    for name in tests::list_test_files("malformed") {
+        let result = parse(&tests::load_test_file(&name));
+
+        assert!(result.is_err(), "{} was parsed successfully", name);
+    }
+}
+
+#[test]
+fn test_error_literal_out_of_range() {
+    let error = parse(&tests::load_test_file("malformed/const_hex_overflow.idl")).unwrap_err();
+
+    assert!(error.kind == ParseErrorKind::IntegerOutOfRange);
+    assert!(error.line == 2);
+    assert!(error.column == 31);
+    assert!(error.context == vec!["interface `Foo`", "member 1"]);
+
+    let error = parse(&tests::load_test_file(
+        "malformed/const_decimal_overflow.idl",
+    ))
+    .unwrap_err();
+
+    assert!(error.kind == ParseErrorKind::DecimalOutOfRange);
+}
+
+#[test]
+fn test_error_nesting_too_deep() {
+    let input = format!(
+        "typedef {}long{} Foo;",
+        "sequence<".repeat(100),
+        ">".repeat(100)
+    );
+    let error = parse(&input).unwrap_err();
+
+    assert!(error.kind == ParseErrorKind::NestingTooDeep);
+}
+
+#[test]
+fn test_recovering() {
+    let (definitions, errors) = parse_recovering(&tests::load_test_file("recovering.idl"));
+
+    assert!(definitions.len() == 2);
+    match &definitions[0] {
+        Definition::Interface(interface) => {
+            assert!(interface.identifier == "Foo");
+            assert!(interface.members.len() == 2);
+        }
+        _ => panic!("Parsed definition is not an interface."),
+    }
+    assert!(definitions[1].get_identifier() == Some("Baz"));
+
+    assert!(errors.len() == 2);
+    assert!(errors[0].line == 3);
+    assert!(errors[0].context == vec!["interface `Foo`", "member 2"]);
+    assert!(errors[1].line == 7);
+    assert!(errors[1].context == vec!["dictionary `Bar`"]);
+}
+
+#[test]
+fn test_recovering_valid_input() {
+    let input = tests::load_test_file("interface_simple.idl");
+    let (definitions, errors) = parse_recovering(&input);
+
+    assert!(definitions.len() == parse(&input).unwrap().len());
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_spans() {
+    let input = tests::load_test_file("spans.idl");
+    let definitions = parse_with_file(&input, "spans.idl").unwrap();
+    let text = |span: Option<&crate::Span>| {
+        let span = span.unwrap();
+        assert!(span.file.as_deref() == Some("spans.idl"));
+        &input[span.start..span.end]
+    };
+
+    assert!(text(definitions[0].get_span()).starts_with("interface Foo {"));
+    assert!(text(definitions[0].get_span()).ends_with("};"));
+    let Definition::Interface(interface) = &definitions[0] else {
+        panic!("Parsed definition is not an interface.");
+    };
+    assert!(text(interface.members[0].get_span()) == "attribute DOMString bar;");
+    let Member::Operation(operation) = &interface.members[1] else {
+        panic!("Parsed member is not an operation.");
+    };
+    assert!(text(operation.r#type.get_span()) == "undefined");
+    assert!(text(operation.arguments[0].span.as_ref()) == "[Clamp] long qux");
+    assert!(text(operation.arguments[0].ext_attrs[0].span.as_ref()) == "Clamp");
+
+    let Definition::Dictionary(dictionary) = &definitions[1] else {
+        panic!("Parsed definition is not a dictionary.");
+    };
+    assert!(text(dictionary.members[0].span.as_ref()) == "required sequence<long> baz;");
+    assert!(text(dictionary.members[0].r#type.get_span()) == "sequence<long>");
+
+    // Without a file name, spans are still recorded.
+    let definitions = parse(&input).unwrap();
+    assert!(definitions[1].get_span().unwrap().file.is_none());
+}
+
+#[test]
+fn test_keyword_boundaries() {
+    let definitions =
+        parse("interface Foo { attribute longer bar; readonly attribute long baz; };").unwrap();
+    let Definition::Interface(interface) = &definitions[0] else {
+        panic!("Parsed definition is not an interface.");
+    };
+    let Member::Attribute(attribute) = &interface.members[0] else {
+        panic!("Parsed member is not an attribute.");
+    };
+
+    assert!(attribute.r#type == Type::from("longer"));
+    assert!(parse("interfaceFoo {};").is_err());
+}
+
+#[test]
+fn test_multi_word_primitive_types() {
+    for input in [
+        "typedef unsigned  long long Foo;",
+        "typedef unsigned\nlong /* comment */ long Foo;",
+        "typedef unsigned // comment\n long\tlong Foo;",
+    ] {
+        let definitions = parse(input).unwrap();
+        let Definition::Typedef(typedef) = &definitions[0] else {
+            panic!("Parsed definition is not a typedef.");
+        };
+        assert!(typedef.r#type == Type::from(PrimitiveType::UnsignedLongLong));
+    }
+}
+
+#[test]
+fn test_multi_word_keywords() {
+    for input in [
+        "interface  mixin Foo {};",
+        "interface\nmixin Foo {};",
+        "callback /* comment */ interface Foo {};",
+    ] {
+        let definitions = parse(input).unwrap();
+        assert!(definitions[0].get_identifier() == Some("Foo"), "{}", input);
+        assert!(
+            matches!(
+                definitions[0],
+                Definition::InterfaceMixin(_) | Definition::CallbackInterface(_)
+            ),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_empty_default_values() {
+    let definitions =
+        parse("dictionary Foo { sequence<long> a = [ ]; Bar b = {\n}; Bar c = {/* */}; };")
+            .unwrap();
+    let Definition::Dictionary(dictionary) = &definitions[0] else {
+        panic!("Parsed definition is not a dictionary.");
+    };
+
+    assert!(dictionary.members[0].default == Some(DefaultValue::Sequence));
+    assert!(dictionary.members[1].default == Some(DefaultValue::Dictionary));
+    assert!(dictionary.members[2].default == Some(DefaultValue::Dictionary));
+}
+
+#[test]
+fn test_primitive_type_at_end_of_input() {
+    let state = crate::parser::State::default();
+    let (rest, r#type) = Type::parse(crate::parser::Input::new_extra(
+        "unrestricted double",
+        &state,
+    ))
+    .unwrap();
+
+    assert!(rest.is_empty());
+    assert!(r#type == Type::from(PrimitiveType::UnrestrictedDouble));
+}
+
+#[test]
+fn test_buffer_types() {
+    let definitions =
+        parse("typedef (SharedArrayBuffer or DataView or Float16Array) Foo;").unwrap();
+    let Definition::Typedef(typedef) = &definitions[0] else {
+        panic!("Parsed definition is not a typedef.");
+    };
+
+    assert!(typedef.r#type.to_string() == "(SharedArrayBuffer or DataView or Float16Array)");
+    let Type::Union(union) = &typedef.r#type else {
+        panic!("Parsed type is not a union.");
+    };
+    assert!(union.types[1] == Type::from(PrimitiveType::DataView));
+}
+
+#[test]
+fn test_numeric_literals() {
+    let definitions = parse(&tests::load_test_file("numeric_literals.idl")).unwrap();
+    let Definition::Interface(interface) = &definitions[0] else {
+        panic!("Parsed definition is not an interface.");
+    };
+    let values: Vec<_> = interface
+        .members
+        .iter()
+        .map(|member| match member {
+            Member::Constant(constant) => constant.value.clone(),
+            _ => panic!("Parsed member is not a constant."),
+        })
+        .collect();
+
+    assert!(matches!(&values[0], ConstValue::Integer(i) if i.value == -32768));
+    assert!(matches!(&values[1], ConstValue::Integer(i) if i.value == 0x1f));
+    assert!(matches!(&values[2], ConstValue::Integer(i) if i.value == 0o777));
+    assert!(matches!(&values[3], ConstValue::Integer(i) if i.value == u64::MAX as i128));
+    assert!(matches!(&values[4], ConstValue::Integer(i) if i.value == i64::MIN as i128));
+    assert!(matches!(&values[5], ConstValue::Decimal(d) if d.value == 1e-3));
+    assert!(matches!(&values[6], ConstValue::Decimal(d) if d.value == -50.0));
+    assert!(matches!(values[7], ConstValue::NegativeInfinity));
+
+    let Definition::Dictionary(dictionary) = &definitions[1] else {
+        panic!("Parsed definition is not a dictionary.");
+    };
+    assert!(matches!(
+        &dictionary.members[0].default,
+        Some(DefaultValue::Integer(i)) if i.value == -1
+    ));
+    assert!(matches!(
+        &dictionary.members[1].default,
+        Some(DefaultValue::Decimal(d)) if d.value == 2.0
+    ));
+}
+
+#[test]
+fn test_integer_below_range() {
+    let error = parse("interface Foo { const long long X = -0x8000000000000001; };").unwrap_err();
+
+    assert!(error.kind == ParseErrorKind::IntegerOutOfRange);
+}
+
+#[test]
+fn test_string_values() {
+    let definitions = parse(&tests::load_test_file("strings.idl")).unwrap();
+    let Definition::Enumeration(r#enum) = &definitions[0] else {
+        panic!("Parsed definition is not an enumeration.");
+    };
+
+    assert!(r#enum.values == vec!["", "naïve", "日本語", "back\\slash"]);
+    assert!(matches!(
+        &r#enum.ext_attrs[0].value,
+        Some(ExtAttrValue::String(string)) if string == "café"
+    ));
+    assert!(matches!(
+        &r#enum.ext_attrs[1].value,
+        Some(ExtAttrValue::IdentifierList(list)) if list == &vec!["Window", "Worker"]
+    ));
+    assert!(matches!(
+        &r#enum.ext_attrs[2].value,
+        Some(ExtAttrValue::StringList(list)) if list[1] == "\\u{e9}"
+    ));
+}
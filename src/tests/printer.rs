@@ -0,0 +1,87 @@
+use crate::{parse, tests, to_string, FormatOptions, IndentStyle, Printer};
+
+#[test]
+fn test_default_options() {
+    for name in tests::list_test_files("") {
+        let name = name.trim_start_matches('/');
+        if !name.ends_with(".idl") {
+            continue;
+        }
+        let Ok(definitions) = parse(&tests::load_test_file(name)) else {
+            continue;
+        };
+
+        assert!(
+            Printer::default().print(&definitions) == to_string(&definitions),
+            "{} is printed differently",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let printers = [
+        Printer::default(),
+        Printer::new(FormatOptions {
+            indent: IndentStyle::Spaces(2),
+            max_width: Some(20),
+            blank_lines: 0,
+            trailing_comma: true,
+        }),
+    ];
+
+    for name in tests::list_test_files("") {
+        let name = name.trim_start_matches('/');
+        if !name.ends_with(".idl") {
+            continue;
+        }
+        let Ok(definitions) = parse(&tests::load_test_file(name)) else {
+            continue;
+        };
+
+        for printer in &printers {
+            let printed = printer.print(&definitions);
+            assert!(
+                parse(&printed).unwrap() == definitions,
+                "{} doesn't round-trip with {:?}",
+                name,
+                printer.options()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_options() {
+    let definitions = parse(
+        "interface Foo { attribute long bar; };
+        enum Mode { \"a\", \"b\" };",
+    )
+    .unwrap();
+    let printer = Printer::new(FormatOptions {
+        indent: IndentStyle::Spaces(2),
+        blank_lines: 2,
+        trailing_comma: true,
+        ..Default::default()
+    });
+
+    assert!(
+        printer.print(&definitions)
+            == "interface Foo {\n  attribute long bar;\n};\n\n\nenum Mode {\n  \"a\",\n  \"b\",\n};"
+    );
+}
+
+#[test]
+fn test_wrapping() {
+    let definitions = parse(&tests::load_test_file("printer.idl")).unwrap();
+    let printer = Printer::new(FormatOptions {
+        indent: IndentStyle::Spaces(2),
+        max_width: Some(60),
+        ..Default::default()
+    });
+
+    let printed = printer.print(&definitions);
+    assert!(printed == tests::load_test_file("printer_wrapped.idl"));
+    assert!(parse(&printed).unwrap() == definitions);
+}
@@ -0,0 +1,99 @@
+use crate::{
+    parse, tests, Callable, Definition, JsValue, Member, OverloadSet, PrimitiveType, SymbolTable,
+};
+
+// Index of the operation `resolve` selects among the members of `Foo`.
+fn resolve(definitions: &[Definition], identifier: &str, arguments: &[JsValue]) -> Option<usize> {
+    let Some(Definition::Interface(foo)) = definitions.last() else {
+        panic!("Parsed definition is not an interface.");
+    };
+    let symbols = SymbolTable::new(definitions);
+    let Callable::Operation(selected) = OverloadSet::operations(&foo.members, identifier)
+        .resolve(arguments, &symbols)
+        .ok()?
+    else {
+        panic!("Callable is not an operation.");
+    };
+
+    foo.members
+        .iter()
+        .position(|member| matches!(member, Member::Operation(operation) if std::ptr::eq(operation, selected)))
+}
+
+#[test]
+fn test_resolve_distinguishing_argument() {
+    let definitions = parse(&tests::load_test_file("resolution.idl")).unwrap();
+    let f = |argument| resolve(&definitions, "f", &[argument]);
+
+    assert!(f(JsValue::PlatformObject("Element".into())) == Some(0));
+    assert!(f(JsValue::String) == Some(1));
+    assert!(f(JsValue::Iterable) == Some(2));
+    assert!(f(JsValue::Callable) == Some(3));
+    assert!(f(JsValue::Object) == Some(4));
+    assert!(f(JsValue::Undefined) == Some(4));
+    assert!(f(JsValue::Null) == Some(4));
+    // Other values are converted to a string.
+    assert!(f(JsValue::Number) == Some(1));
+    assert!(f(JsValue::BufferSource(PrimitiveType::ArrayBuffer)) == Some(4));
+    assert!(f(JsValue::Symbol).is_none());
+    // Extra arguments are ignored.
+    assert!(resolve(&definitions, "f", &[JsValue::String, JsValue::Number]) == Some(1));
+}
+
+#[test]
+fn test_resolve_argument_count() {
+    let definitions = parse(&tests::load_test_file("resolution.idl")).unwrap();
+    let g = |arguments: &[JsValue]| resolve(&definitions, "g", arguments);
+
+    assert!(g(&[JsValue::Number]) == Some(5));
+    assert!(g(&[JsValue::Number, JsValue::String]) == Some(5));
+    assert!(g(&[JsValue::Number, JsValue::Boolean]) == Some(6));
+    assert!(
+        g(&[
+            JsValue::Number,
+            JsValue::Boolean,
+            JsValue::Number,
+            JsValue::Number
+        ]) == Some(6)
+    );
+    // Only the variadic overload takes three arguments.
+    assert!(g(&[JsValue::Number, JsValue::String, JsValue::Number]) == Some(6));
+    assert!(g(&[]).is_none());
+}
+
+#[test]
+fn test_resolve_type_error() {
+    let definitions = parse(&tests::load_test_file("resolution.idl")).unwrap();
+    let Some(Definition::Interface(foo)) = definitions.last() else {
+        panic!("Parsed definition is not an interface.");
+    };
+    let symbols = SymbolTable::new(&definitions);
+    let error = |identifier, arguments: &[JsValue]| {
+        OverloadSet::operations(&foo.members, identifier)
+            .resolve(arguments, &symbols)
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert!(error("g", &[]) == "TypeError: no overload takes 0 arguments");
+    assert!(
+        error("h", &[JsValue::PlatformObject("Node".into())])
+            == "TypeError: argument 0 doesn't match any overload"
+    );
+    assert!(
+        error("i", &[JsValue::Number]) == "TypeError: argument 0 can't be converted to `bigint`"
+    );
+    // A callback function with `[LegacyTreatNonObjectAsNull]` accepts dictionaries as well.
+    assert!(
+        error("j", &[JsValue::Callable])
+            == "TypeError: overloads with 1 arguments can't be distinguished"
+    );
+    assert!(
+        resolve(
+            &definitions,
+            "h",
+            &[JsValue::PlatformObject("Element".into())]
+        ) == Some(7)
+    );
+    assert!(resolve(&definitions, "i", &[JsValue::Symbol]) == Some(9));
+}
@@ -0,0 +1,67 @@
+use serde_json::json;
+
+use crate::{
+    parse_with_file, tests,
+    visit::{self, Visit},
+    Definition, Span, StringLiteral,
+};
+
+// Collects the spans of all nodes.
+#[derive(Default)]
+struct Spans<'ast>(Vec<&'ast Span>);
+
+impl<'ast> Visit<'ast> for Spans<'ast> {
+    fn visit_span(&mut self, node: &'ast Span) {
+        self.0.push(node);
+        visit::visit_span(self, node);
+    }
+}
+
+fn spans(definitions: &[Definition]) -> Vec<&Span> {
+    let mut spans = Spans::default();
+    for definition in definitions {
+        spans.visit_definition(definition);
+    }
+    spans.0
+}
+
+#[test]
+fn test_round_trip() {
+    for name in tests::list_test_files("") {
+        let name = name.trim_start_matches('/');
+        if !name.ends_with(".idl") {
+            continue;
+        }
+        let Ok(definitions) = parse_with_file(&tests::load_test_file(name), name) else {
+            continue;
+        };
+
+        let json = serde_json::to_string(&definitions).unwrap();
+        let deserialized: Vec<Definition> = serde_json::from_str(&json).unwrap();
+        assert!(deserialized == definitions, "{} doesn't round-trip", name);
+        assert!(spans(&deserialized) == spans(&definitions));
+    }
+}
+
+#[test]
+fn test_representation() {
+    let definitions =
+        crate::parse("[Exposed=*, Foo(long a)] typedef sequence<long>? Bar;").unwrap();
+    let json = serde_json::to_value(&definitions[0]).unwrap();
+
+    assert!(json["kind"] == "typedef");
+    assert!(json["value"]["identifier"] == "Bar");
+    assert!(json["value"]["ext_attrs"][0]["value"] == json!({ "kind": "wildcard" }));
+    assert!(json["value"]["ext_attrs"][1]["value"]["kind"] == "argument_list");
+    let r#type = &json["value"]["type"];
+    assert!(r#type["kind"] == "sequence");
+    assert!(r#type["value"]["nullable"] == true);
+    assert!(r#type["value"]["type"]["kind"] == "standard");
+    assert!(r#type["value"]["type"]["value"]["name"] == json!({ "Primitive": "Long" }));
+}
+
+#[test]
+fn test_invalid_string_literal() {
+    assert!(serde_json::from_value::<StringLiteral>(json!("foo")).unwrap() == "foo");
+    assert!(serde_json::from_value::<StringLiteral>(json!("\"foo\"")).is_err());
+}
@@ -0,0 +1,52 @@
+use crate::{parse, tests, Definition, Member, SymbolKind, SymbolTable};
+
+#[test]
+fn test_symbol_kinds() {
+    let definitions = parse(&tests::load_test_file("symbols.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let kind = |identifier| symbols.get(identifier).map(|symbol| symbol.kind);
+
+    assert!(kind("Foo") == Some(SymbolKind::Interface));
+    assert!(kind("Bar") == Some(SymbolKind::Dictionary));
+    assert!(kind("Qux") == Some(SymbolKind::Enumeration));
+    assert!(kind("Quux") == Some(SymbolKind::Typedef));
+    assert!(kind("Corge") == Some(SymbolKind::CallbackFunction));
+    assert!(kind("Grault") == Some(SymbolKind::CallbackInterface));
+    assert!(kind("Garply") == Some(SymbolKind::InterfaceMixin));
+    assert!(kind("Waldo") == Some(SymbolKind::Namespace));
+    assert!(kind("Fred").is_none());
+    assert!(symbols.iter().count() == 8);
+}
+
+#[test]
+fn test_symbol_partials() {
+    let definitions = parse(&tests::load_test_file("symbols.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let foo = symbols.get("Foo").unwrap();
+
+    // The partial dictionary isn't merged into the interface.
+    assert!(foo.partials.len() == 2);
+    assert!(!foo.main_definition().is_partial());
+    assert!(foo.definitions().count() == 3);
+}
+
+#[test]
+fn test_symbol_resolve() {
+    let definitions = parse(&tests::load_test_file("symbols.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let Definition::Interface(foo) = &definitions[1] else {
+        panic!("Parsed definition is not an interface.");
+    };
+    let types: Vec<_> = foo
+        .members
+        .iter()
+        .map(|member| match member {
+            Member::Attribute(attribute) => &attribute.r#type,
+            _ => panic!("Parsed member is not an attribute."),
+        })
+        .collect();
+
+    assert!(symbols.resolve(types[0]).unwrap().identifier == "Bar");
+    assert!(symbols.resolve(types[1]).is_none());
+    assert!(symbols.resolve(types[2]).unwrap().kind == SymbolKind::Enumeration);
+}
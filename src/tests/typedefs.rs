@@ -0,0 +1,69 @@
+use crate::{
+    parse, tests, Definition, Member, PrimitiveType, StandardTypeName, SymbolTable, Type,
+    TypedefCycle,
+};
+
+fn attribute_types(definitions: &[Definition]) -> Vec<&Type> {
+    let Some(Definition::Interface(foo)) = definitions.last() else {
+        panic!("Parsed definition is not an interface.");
+    };
+    foo.members
+        .iter()
+        .map(|member| match member {
+            Member::Attribute(attribute) => &attribute.r#type,
+            _ => panic!("Parsed member is not an attribute."),
+        })
+        .collect()
+}
+
+#[test]
+fn test_expand_typedefs() {
+    let definitions = parse(&tests::load_test_file("typedefs.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let types = attribute_types(&definitions);
+
+    // `Baz` is `Bar?`, which is a nullable union.
+    let Type::Union(baz) = types[0].expand(&symbols).unwrap() else {
+        panic!("Expanded type is not a union.");
+    };
+    assert!(baz.nullable);
+    assert!(baz.types[0] == PrimitiveType::DOMString.into());
+    let Type::Sequence(sequence) = &baz.types[1] else {
+        panic!("Expanded type is not a sequence.");
+    };
+    assert!(*sequence.r#type == "Foo".into());
+
+    // Nested typedefs are expanded too, keeping the annotations of the reference.
+    let Type::Promise(promise) = types[1].expand(&definitions[..]).unwrap() else {
+        panic!("Expanded type is not a promise.");
+    };
+    let Type::Standard(qux) = *promise.r#type else {
+        panic!("Expanded type is not a standard type.");
+    };
+    assert!(qux.name == StandardTypeName::Primitive(PrimitiveType::Long));
+    assert!(qux.nullable);
+    assert!(qux.ext_attrs[0].identifier == "Clamp");
+
+    // Identifiers that aren't typedefs are left as they are.
+    assert!(types[3].expand(&symbols).unwrap() == "Fred".into());
+}
+
+#[test]
+fn test_expand_typedef_cycle() {
+    let definitions = parse(&tests::load_test_file("typedefs.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+
+    let error = attribute_types(&definitions)[2]
+        .expand(&symbols)
+        .unwrap_err();
+    assert!(
+        error
+            == TypedefCycle {
+                identifiers: vec!["Grault".into(), "Corge".into(), "Grault".into()],
+            }
+    );
+    assert!(error.to_string() == "typedef refers to itself: Grault -> Corge -> Grault");
+
+    let quux: Type = "Quux".into();
+    assert!(quux.expand(&symbols).unwrap_err().identifiers == ["Quux", "Quux"]);
+}
@@ -0,0 +1,87 @@
+use crate::{
+    parse, tests, validate, Definition, Member, PrimitiveType, Rule, SymbolTable, Type, UnionType,
+};
+
+fn unions(definitions: &[Definition]) -> Vec<&UnionType> {
+    let Some(Definition::Interface(foo)) = definitions.last() else {
+        panic!("Parsed definition is not an interface.");
+    };
+    foo.members
+        .iter()
+        .map(|member| match member {
+            Member::Attribute(attribute) => match &attribute.r#type {
+                Type::Union(union) => union,
+                _ => panic!("Parsed type is not a union."),
+            },
+            _ => panic!("Parsed member is not an attribute."),
+        })
+        .collect()
+}
+
+#[test]
+fn test_flattened_member_types() {
+    let definitions = parse(&tests::load_test_file("unions.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let unions = unions(&definitions);
+
+    let types = unions[0].flattened_member_types();
+    assert!(
+        types
+            == [
+                PrimitiveType::DOMString.into(),
+                PrimitiveType::Long.into(),
+                "Foo".into()
+            ]
+    );
+    // Annotations and nullability are dropped.
+    let Type::Standard(long) = &types[1] else {
+        panic!("Flattened type is not a standard type.");
+    };
+    assert!(!long.nullable && long.ext_attrs.is_empty());
+
+    assert!(unions[1].flattened_member_types().len() == 2);
+    let types = unions[1].flattened_member_types_with(&symbols);
+    assert!(types.len() == 3);
+    assert!(types[0] == PrimitiveType::Long.into());
+    assert!(types[1] == "Foo".into());
+    assert!(matches!(types[2], Type::Sequence(_)));
+}
+
+#[test]
+fn test_nullable_member_types() {
+    let definitions = parse(&tests::load_test_file("unions.idl")).unwrap();
+    let symbols = SymbolTable::new(&definitions);
+    let unions = unions(&definitions);
+
+    assert!(unions[0].nullable_member_types() == 1);
+    assert!(unions[1].nullable_member_types() == 0);
+    assert!(unions[1].nullable_member_types_with(&symbols) == 1);
+    assert!(unions[2].nullable_member_types() == 2);
+    assert!(unions[3].nullable_member_types_with(&symbols) == 1);
+}
+
+#[test]
+fn test_validate_nullable_union() {
+    let definitions = parse(&tests::load_test_file("unions.idl")).unwrap();
+    let diagnostics: Vec<_> = validate(&definitions)
+        .into_iter()
+        .map(|d| {
+            assert!(d.rule == Rule::NullableUnion);
+            (d.member.unwrap(), d.message)
+        })
+        .collect();
+
+    assert!(
+        diagnostics
+            == [
+                (
+                    "c".to_string(),
+                    "union has more than one nullable member type".to_string()
+                ),
+                (
+                    "d".to_string(),
+                    "nullable union has a nullable member type".to_string()
+                ),
+            ]
+    );
+}
@@ -1,5 +1,7 @@
 use std::fs;
 
+use crate::{parser, Type};
+
 pub(super) fn load_test_file(name: &str) -> String {
     fs::read_to_string(&format!(
         "{}/resources/{}",
@@ -8,3 +10,21 @@ pub(super) fn load_test_file(name: &str) -> String {
     ))
     .expect("Failed to read asset file")
 }
+
+pub(super) fn list_test_files(dir: &str) -> Vec<String> {
+    let mut names: Vec<String> =
+        fs::read_dir(format!("{}/resources/{}", env!("CARGO_MANIFEST_DIR"), dir))
+            .expect("Failed to read asset directory")
+            .map(|entry| format!("{}/{}", dir, entry.unwrap().file_name().to_string_lossy()))
+            .collect();
+
+    names.sort();
+    names
+}
+
+// Parses a type on its own, such as `sequence<long>`.
+pub(super) fn parse_type(input: &str) -> Type {
+    let state = parser::State::default();
+    let (_, r#type) = Type::parse(parser::Input::new_extra(input, &state)).unwrap();
+    r#type
+}
@@ -0,0 +1,92 @@
+use crate::{parse, tests, validate, Rule, Severity};
+
+#[test]
+fn test_validate_valid() {
+    for name in [
+        "interface_simple.idl",
+        "dictionary_simple.idl",
+        "numeric_literals.idl",
+    ] {
+        let definitions = parse(&tests::load_test_file(name)).unwrap();
+
+        assert!(validate(&definitions).is_empty(), "{} is invalid", name);
+    }
+}
+
+#[test]
+fn test_validate_rules() {
+    let definitions = parse(&tests::load_test_file("validation.idl")).unwrap();
+    let diagnostics: Vec<_> = validate(&definitions)
+        .into_iter()
+        .map(|d| {
+            assert!(d.severity == Severity::Error);
+            (d.rule, d.definition, d.member)
+        })
+        .collect();
+    let at = |rule, definition: &str, member: Option<&str>| {
+        (
+            rule,
+            Some(definition.to_string()),
+            member.map(str::to_string),
+        )
+    };
+
+    assert!(
+        diagnostics
+            == vec![
+                at(Rule::DuplicateDefinition, "Foo", None),
+                at(Rule::MissingDefinition, "Missing", None),
+                at(Rule::InvalidInheritance, "Foo", None),
+                at(Rule::DuplicateMember, "Foo", Some("baz")),
+                at(Rule::InvalidMember, "Foo", None),
+                at(Rule::InvalidInheritance, "Bar", None),
+                at(Rule::UndefinedType, "Bar", Some("x")),
+                at(Rule::UndefinedType, "Bar", Some("y")),
+                at(Rule::DuplicateMember, "Bar", Some("x")),
+                at(Rule::InvalidMember, "FooMixin", None),
+                at(Rule::InvalidMember, "FooMixin", Some("foo")),
+                at(Rule::InvalidMember, "Callback", Some("foo")),
+                at(Rule::InvalidMember, "Namespace", Some("foo")),
+                at(Rule::DuplicateEnumValue, "Baz", None),
+                at(Rule::InvalidIncludes, "Bar", None),
+                at(Rule::InvalidIncludes, "Bar", None),
+            ]
+    );
+}
+
+#[test]
+fn test_diagnostic_display() {
+    let definitions = parse("dictionary Foo { Bar bar; };").unwrap();
+    let diagnostics = validate(&definitions);
+
+    assert!(diagnostics[0].rule.code() == "undefined-type");
+    assert!(
+        diagnostics[0].to_string() == "error[undefined-type]: `Bar` is not defined (in `Foo.bar`)"
+    );
+}
+
+#[test]
+fn test_validate_warnings() {
+    let definitions =
+        parse("enum Foo { \"a-b\", \"2d\", \"\", \"fooBar\", \"foo_bar\" };").unwrap();
+    let diagnostics = validate(&definitions);
+
+    assert!(diagnostics.len() == 2);
+    assert!(diagnostics.iter().all(|d| d.rule == Rule::EnumValueCase));
+    assert!(diagnostics.iter().all(|d| d.severity == Severity::Warning));
+    assert!(
+        diagnostics[0].to_string()
+            == "warning[enum-value-case]: \"fooBar\" should be lowercase, with words separated by \
+                dashes (in `Foo`)"
+    );
+}
+
+#[test]
+fn test_validate_partial_of_different_kind() {
+    let definitions = parse(&tests::load_test_file("symbols.idl")).unwrap();
+    let diagnostics = validate(&definitions);
+
+    assert!(diagnostics.len() == 1);
+    assert!(diagnostics[0].rule == Rule::DuplicateDefinition);
+    assert!(diagnostics[0].definition.as_deref() == Some("Foo"));
+}
@@ -0,0 +1,100 @@
+use crate::{
+    parse, tests,
+    visit::{self, Visit},
+    visit_mut::{self, VisitMut},
+    Argument, StandardType, StandardTypeName, Type,
+};
+
+// Collects the identifiers of all types and arguments, in the order they are visited.
+#[derive(Default)]
+struct Collector<'ast> {
+    types: Vec<&'ast str>,
+    arguments: Vec<&'ast str>,
+}
+
+impl<'ast> Visit<'ast> for Collector<'ast> {
+    fn visit_standard_type(&mut self, node: &'ast StandardType) {
+        if let StandardTypeName::Identifier(identifier) = &node.name {
+            self.types.push(identifier);
+        }
+        visit::visit_standard_type(self, node);
+    }
+
+    fn visit_argument(&mut self, node: &'ast Argument) {
+        self.arguments.push(&node.identifier);
+        visit::visit_argument(self, node);
+    }
+}
+
+#[test]
+fn test_visit() {
+    let definitions = parse(&tests::load_test_file("visit.idl")).unwrap();
+    let mut collector = Collector::default();
+    for definition in &definitions {
+        collector.visit_definition(definition);
+    }
+
+    assert!(collector.types == ["Bar", "Baz", "Qux", "Quux", "Options", "Corge"]);
+    // Including the arguments of extended attributes.
+    assert!(collector.arguments == ["width", "values", "rest", "options", "value"]);
+}
+
+#[test]
+fn test_visit_override_stops_walk() {
+    struct Shallow(usize);
+
+    impl<'ast> Visit<'ast> for Shallow {
+        fn visit_type(&mut self, _: &'ast Type) {
+            self.0 += 1;
+        }
+    }
+
+    let definitions = parse(&tests::load_test_file("visit.idl")).unwrap();
+    let mut shallow = Shallow(0);
+    for definition in &definitions {
+        shallow.visit_definition(definition);
+    }
+
+    // Types nested in other types aren't visited, as the override doesn't walk into them.
+    assert!(shallow.0 == 12);
+}
+
+#[test]
+fn test_visit_mut() {
+    struct Rename;
+
+    impl VisitMut for Rename {
+        fn visit_standard_type_mut(&mut self, node: &mut StandardType) {
+            if let StandardTypeName::Identifier(identifier) = &mut node.name {
+                identifier.insert_str(0, "Renamed");
+            }
+            visit_mut::visit_standard_type_mut(self, node);
+        }
+
+        fn visit_argument_mut(&mut self, node: &mut Argument) {
+            node.identifier.make_ascii_uppercase();
+            visit_mut::visit_argument_mut(self, node);
+        }
+    }
+
+    let mut definitions = parse(&tests::load_test_file("visit.idl")).unwrap();
+    for definition in &mut definitions {
+        Rename.visit_definition_mut(definition);
+    }
+
+    let expected = parse(
+        &tests::load_test_file("visit.idl")
+            .replace("Bar", "RenamedBar")
+            .replace("Baz", "RenamedBaz")
+            .replace("Qux", "RenamedQux")
+            .replace("Quux", "RenamedQuux")
+            .replace("Options options", "RenamedOptions OPTIONS")
+            .replace("Corge", "RenamedCorge")
+            .replace("width", "WIDTH")
+            .replace("values", "VALUES")
+            .replace("rest", "REST")
+            .replace("value)", "VALUE)"),
+    )
+    .unwrap();
+    assert!(definitions == expected);
+}
@@ -0,0 +1,91 @@
+use serde_json::{json, Value};
+
+use crate::{from_webidl2_json, parse, tests, to_webidl2_json};
+
+#[test]
+fn test_to_webidl2_json() {
+    let definitions = parse(&tests::load_test_file("webidl2.idl")).unwrap();
+    let expected: Value = serde_json::from_str(&tests::load_test_file("webidl2.json")).unwrap();
+
+    assert!(to_webidl2_json(&definitions) == expected);
+}
+
+#[test]
+fn test_from_webidl2_json() {
+    let json: Value = serde_json::from_str(&tests::load_test_file("webidl2.json")).unwrap();
+    let expected = parse(&tests::load_test_file("webidl2.idl")).unwrap();
+
+    assert!(from_webidl2_json(&json).unwrap() == expected);
+}
+
+#[test]
+fn test_webidl2_round_trip() {
+    for name in tests::list_test_files("") {
+        let name = name.trim_start_matches('/');
+        if !name.ends_with(".idl") {
+            continue;
+        }
+        let Ok(definitions) = parse(&tests::load_test_file(name)) else {
+            continue;
+        };
+
+        let round_trip = from_webidl2_json(&to_webidl2_json(&definitions));
+        assert!(
+            round_trip.as_deref() == Ok(&definitions[..]),
+            "{} doesn't round-trip",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_from_webidl2_json_errors() {
+    let from = |json: Value| from_webidl2_json(&json).map_err(|error| error.to_string());
+
+    assert!(
+        from(json!({}))
+            == Err("invalid webidl2.js JSON: expected an array of definitions".to_string())
+    );
+    assert!(
+        from(json!([{ "type": "module", "name": "Foo" }]))
+            == Err("invalid webidl2.js JSON: unknown definition type `module`".to_string())
+    );
+    assert!(
+        from(json!([{ "type": "interface", "members": [] }]))
+            == Err("invalid webidl2.js JSON: `interface` has no `name`".to_string())
+    );
+    assert!(
+        from(json!([{
+            "type": "interface",
+            "name": "Foo",
+            "members": [],
+            "extAttrs": [{
+                "type": "extended-attribute",
+                "name": "Version",
+                "rhs": { "type": "integer", "value": "1" },
+                "arguments": [],
+            }],
+        }])) == Err(
+            "invalid webidl2.js JSON: value of type `integer` of `extended-attribute` isn't \
+            supported"
+                .to_string()
+        )
+    );
+    assert!(
+        from(json!([{
+            "type": "typedef",
+            "name": "Foo",
+            "idlType": {
+                "type": "typedef-type",
+                "generic": "sequence",
+                "idlType": [{ "type": null, "idlType": "long" }],
+            },
+        }])) == Err(
+            "invalid webidl2.js JSON: expected `typedef-type` for `type` of `idlType`".to_string()
+        )
+    );
+    // The end of file is skipped.
+    assert!(from(json!([{ "type": "eof", "value": "" }]))
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,125 @@
+use std::fmt;
+
+use crate::{Definition, StandardTypeName, SymbolTable, Type, Typedef};
+
+/// Looks up typedefs by their identifier, for [`Type::expand`].
+pub trait TypedefResolver {
+    fn typedef(&self, identifier: &str) -> Option<&Typedef>;
+}
+
+/// Error of [`Type::expand`]: a typedef refers to itself, directly or through other typedefs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedefCycle {
+    /// Identifiers of the typedefs in the cycle, starting and ending with the same one.
+    pub identifiers: Vec<String>,
+}
+
+/* Functionality implementations */
+
+impl Type {
+    /// Replaces every identifier that refers to a typedef with the type it stands for, including
+    /// those nested in other types and those the typedefs refer to themselves.
+    ///
+    /// If a reference to a typedef is nullable, so is the type it expands to, and its extended
+    /// attributes are added to those of the type it expands to. Both are dropped if the type
+    /// can't have them, such as extended attributes on a `sequence<T>`.
+    pub fn expand<R: TypedefResolver + ?Sized>(&self, resolver: &R) -> Result<Type, TypedefCycle> {
+        let mut expanded = self.clone();
+        expand(&mut expanded, resolver, &mut vec![])?;
+        Ok(expanded)
+    }
+}
+
+// `expanding` holds the identifiers of the typedefs that are currently being expanded.
+fn expand<R: TypedefResolver + ?Sized>(
+    r#type: &mut Type,
+    resolver: &R,
+    expanding: &mut Vec<String>,
+) -> Result<(), TypedefCycle> {
+    let standard = match r#type {
+        Type::Sequence(sequence) => return expand(&mut sequence.r#type, resolver, expanding),
+        Type::Record(record) => return expand(&mut record.value, resolver, expanding),
+        Type::Promise(promise) => return expand(&mut promise.r#type, resolver, expanding),
+        Type::Union(union) => {
+            for r#type in &mut union.types {
+                expand(r#type, resolver, expanding)?;
+            }
+            return Ok(());
+        }
+        Type::FrozenArray(frozen_array) => {
+            return expand(&mut frozen_array.r#type, resolver, expanding)
+        }
+        Type::ObservableArray(observable_array) => {
+            return expand(&mut observable_array.r#type, resolver, expanding)
+        }
+        Type::Standard(standard) => standard,
+    };
+
+    let StandardTypeName::Identifier(identifier) = &standard.name else {
+        return Ok(());
+    };
+    let Some(typedef) = resolver.typedef(identifier) else {
+        return Ok(());
+    };
+    if let Some(start) = expanding.iter().position(|i| i == identifier) {
+        let mut identifiers = expanding[start..].to_vec();
+        identifiers.push(identifier.clone());
+        return Err(TypedefCycle { identifiers });
+    }
+
+    expanding.push(identifier.clone());
+    let mut expanded = typedef.r#type.clone();
+    expand(&mut expanded, resolver, expanding)?;
+    expanding.pop();
+
+    match &mut expanded {
+        Type::Union(union) => {
+            union.ext_attrs.append(&mut standard.ext_attrs);
+            union.nullable |= standard.nullable;
+        }
+        Type::Standard(expanded) => {
+            expanded.ext_attrs.append(&mut standard.ext_attrs);
+            expanded.nullable |= standard.nullable;
+        }
+        Type::Sequence(sequence) => sequence.nullable |= standard.nullable,
+        Type::Promise(promise) => promise.nullable |= standard.nullable,
+        Type::FrozenArray(frozen_array) => frozen_array.nullable |= standard.nullable,
+        Type::ObservableArray(observable_array) => observable_array.nullable |= standard.nullable,
+        Type::Record(_) => {}
+    }
+    *r#type = expanded;
+
+    Ok(())
+}
+
+/* Trait implementations */
+
+impl TypedefResolver for SymbolTable<'_> {
+    fn typedef(&self, identifier: &str) -> Option<&Typedef> {
+        match self.get(identifier)?.definition {
+            Some(Definition::Typedef(typedef)) => Some(typedef),
+            _ => None,
+        }
+    }
+}
+
+impl TypedefResolver for [Definition] {
+    fn typedef(&self, identifier: &str) -> Option<&Typedef> {
+        self.iter().find_map(|definition| match definition {
+            Definition::Typedef(typedef) if typedef.identifier == identifier => Some(typedef),
+            _ => None,
+        })
+    }
+}
+
+impl fmt::Display for TypedefCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "typedef refers to itself: {}",
+            self.identifiers.join(" -> ")
+        )
+    }
+}
+
+impl std::error::Error for TypedefCycle {}
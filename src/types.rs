@@ -1,11 +1,13 @@
-use std::{
-    collections::hash_map::DefaultHasher,
-    hash::{Hash, Hasher},
-};
-
-use crate::ExtendedAttribute;
+use crate::{ExtendedAttribute, Span, Typedef, TypedefResolver};
 
+/// With the `serde` feature, types are serialized as `{"kind": "sequence", "value": {..}}`, where
+/// `kind` is the variant in snake case.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
 pub enum Type {
     Sequence(SequenceType),
     Record(RecordType),
@@ -17,27 +19,34 @@ pub enum Type {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SequenceType {
     pub r#type: Box<Type>,
     pub nullable: bool,
+    pub span: Option<Span>,
 }
 
 // AFAIU, only Union and StandardType can have extended attributes.
 // https://webidl.spec.whatwg.org/#idl-annotated-types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnionType {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub types: Vec<Type>,
     pub nullable: bool,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RecordType {
     pub key: RecordTypeKey,
     pub value: Box<Type>,
+    pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RecordTypeKey {
     DOMString,
     USVString,
@@ -45,37 +54,47 @@ pub enum RecordTypeKey {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PromiseType {
     pub r#type: Box<Type>,
     pub nullable: bool,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FrozenArrayType {
     pub r#type: Box<Type>,
     pub nullable: bool,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObservableArrayType {
     pub r#type: Box<Type>,
     pub nullable: bool,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StandardType {
     pub ext_attrs: Vec<ExtendedAttribute>,
     pub name: StandardTypeName,
     pub nullable: bool,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StandardTypeName {
     Primitive(PrimitiveType),
     Identifier(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PrimitiveType {
     Any,
     Undefined,
@@ -99,6 +118,8 @@ pub enum PrimitiveType {
     Object,
     Symbol,
     ArrayBuffer,
+    SharedArrayBuffer,
+    DataView,
     Int8Array,
     Int16Array,
     Int32Array,
@@ -108,150 +129,206 @@ pub enum PrimitiveType {
     Uint8ClampedArray,
     BigInt64Array,
     BigUint64Array,
+    Float16Array,
     Float32Array,
     Float64Array,
 }
 
 /* Functionality implementations */
 
-// ...
-// ...
-
-/* Trait implementations */
-
-impl From<String> for Type {
-    fn from(identifier: String) -> Self {
-        Self::Standard(StandardType {
-            ext_attrs: vec![],
-            name: StandardTypeName::Identifier(identifier),
-            nullable: false,
-        })
+impl Type {
+    pub fn get_span(&self) -> Option<&Span> {
+        match self {
+            Type::Sequence(sequence) => sequence.span.as_ref(),
+            Type::Record(record) => record.span.as_ref(),
+            Type::Promise(promise) => promise.span.as_ref(),
+            Type::Union(union) => union.span.as_ref(),
+            Type::FrozenArray(frozen_array) => frozen_array.span.as_ref(),
+            Type::ObservableArray(observable_array) => observable_array.span.as_ref(),
+            Type::Standard(standard) => standard.span.as_ref(),
+        }
     }
-}
 
-impl From<&str> for Type {
-    fn from(identifier: &str) -> Self {
-        identifier.to_string().into()
+    pub fn get_span_mut(&mut self) -> &mut Option<Span> {
+        match self {
+            Type::Sequence(sequence) => &mut sequence.span,
+            Type::Record(record) => &mut record.span,
+            Type::Promise(promise) => &mut promise.span,
+            Type::Union(union) => &mut union.span,
+            Type::FrozenArray(frozen_array) => &mut frozen_array.span,
+            Type::ObservableArray(observable_array) => &mut observable_array.span,
+            Type::Standard(standard) => &mut standard.span,
+        }
     }
-}
 
-impl PartialEq for SequenceType {
-    fn eq(&self, other: &Self) -> bool {
-        self.r#type == other.r#type
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            Type::Sequence(sequence) => sequence.nullable,
+            Type::Record(_) => false,
+            Type::Promise(promise) => promise.nullable,
+            Type::Union(union) => union.nullable,
+            Type::FrozenArray(frozen_array) => frozen_array.nullable,
+            Type::ObservableArray(observable_array) => observable_array.nullable,
+            Type::Standard(standard) => standard.nullable,
+        }
     }
-}
 
-impl Eq for SequenceType {}
-
-impl Hash for SequenceType {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.r#type.hash(state);
+    /// Whether the types are the same when their annotations are ignored, that is whether they
+    /// are nullable and their extended attributes, also those of the types they are made of. So
+    /// `[Clamp] long?` is the same as `long`. Everything else is compared, including the keys of
+    /// record types. Unions are the same if they have the same member types, in any order, and
+    /// each of them the same number of times.
+    pub fn eq_ignoring_annotations(&self, other: &Type) -> bool {
+        match (self, other) {
+            (Type::Sequence(a), Type::Sequence(b)) => a.r#type.eq_ignoring_annotations(&b.r#type),
+            (Type::Record(a), Type::Record(b)) => {
+                a.key == b.key && a.value.eq_ignoring_annotations(&b.value)
+            }
+            (Type::Promise(a), Type::Promise(b)) => a.r#type.eq_ignoring_annotations(&b.r#type),
+            (Type::Union(a), Type::Union(b)) => {
+                let count = |types: &[Type], r#type: &Type| {
+                    types
+                        .iter()
+                        .filter(|other| other.eq_ignoring_annotations(r#type))
+                        .count()
+                };
+                a.types.len() == b.types.len()
+                    && a.types
+                        .iter()
+                        .all(|r#type| count(&a.types, r#type) == count(&b.types, r#type))
+            }
+            (Type::FrozenArray(a), Type::FrozenArray(b)) => {
+                a.r#type.eq_ignoring_annotations(&b.r#type)
+            }
+            (Type::ObservableArray(a), Type::ObservableArray(b)) => {
+                a.r#type.eq_ignoring_annotations(&b.r#type)
+            }
+            (Type::Standard(a), Type::Standard(b)) => a.name == b.name,
+            _ => false,
+        }
     }
-}
 
-impl PartialEq for RecordType {
-    fn eq(&self, other: &Self) -> bool {
-        self.value == other.value
+    // Turns `[Foo] T?` into `T`. Returns whether the type was nullable.
+    fn strip_annotations(&mut self) -> bool {
+        let nullable = self.is_nullable();
+        match self {
+            Type::Sequence(sequence) => sequence.nullable = false,
+            Type::Record(_) => {}
+            Type::Promise(promise) => promise.nullable = false,
+            Type::Union(union) => {
+                union.ext_attrs.clear();
+                union.nullable = false;
+            }
+            Type::FrozenArray(frozen_array) => frozen_array.nullable = false,
+            Type::ObservableArray(observable_array) => observable_array.nullable = false,
+            Type::Standard(standard) => {
+                standard.ext_attrs.clear();
+                standard.nullable = false;
+            }
+        }
+        nullable
     }
 }
 
-impl Eq for RecordType {}
-
-impl Hash for RecordType {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.value.hash(state);
+impl UnionType {
+    /// The flattened member types of the union, as defined in:
+    /// <https://webidl.spec.whatwg.org/#dfn-flattened-union-member-types>
+    ///
+    /// Nested unions are looked through, and the member types are neither nullable nor annotated.
+    /// Every type is only included once.
+    pub fn flattened_member_types(&self) -> Vec<Type> {
+        self.flattened_member_types_with(&NoTypedefs)
     }
-}
 
-impl PartialEq for PromiseType {
-    fn eq(&self, other: &Self) -> bool {
-        self.r#type == other.r#type
+    /// Like [`UnionType::flattened_member_types`], but typedefs are expanded first, so that unions
+    /// behind them are looked through as well.
+    pub fn flattened_member_types_with<R: TypedefResolver + ?Sized>(
+        &self,
+        resolver: &R,
+    ) -> Vec<Type> {
+        let mut types = vec![];
+        self.flatten(resolver, &mut types);
+        types
     }
-}
 
-impl Eq for PromiseType {}
-
-impl Hash for PromiseType {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.r#type.hash(state);
+    /// The number of nullable member types of the union, as defined in:
+    /// <https://webidl.spec.whatwg.org/#dfn-number-of-nullable-member-types>
+    pub fn nullable_member_types(&self) -> usize {
+        self.nullable_member_types_with(&NoTypedefs)
     }
-}
 
-// Unions are equal if:
-// 1) The number of possible types match.
-// 2) They contain the same types, regardless of order.
-//
-// This also makes sure that the following property holds true:
-// k1 == k2 -> hash(k1) == hash(k2)
-impl PartialEq for UnionType {
-    fn eq(&self, other: &Self) -> bool {
-        self.types.len() == other.types.len()
-            && self
-                .types
-                .iter()
-                .all(|r#type| other.types.iter().any(|other_type| r#type == other_type))
+    /// Like [`UnionType::nullable_member_types`], but typedefs are expanded first, so that
+    /// typedefs of nullable types are counted as well.
+    pub fn nullable_member_types_with<R: TypedefResolver + ?Sized>(&self, resolver: &R) -> usize {
+        self.flatten(resolver, &mut vec![])
     }
-}
 
-impl Eq for UnionType {}
+    // Adds the flattened member types to `types` and returns the number of nullable member types.
+    fn flatten<R: TypedefResolver + ?Sized>(&self, resolver: &R, types: &mut Vec<Type>) -> usize {
+        let mut nullable = 0;
 
-// For the union type, we want that the hash for `(Foo or Bar)` == `(Bar or Foo)`,
-// therefore we first compute the hash for each possible type, put them in a vector,
-// sort it and use the resulting vector to calculate the final hash. ¯\_(ツ)_/¯
-//
-// NOTE: Can we optimize this?
-impl Hash for UnionType {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        let mut hash_values = vec![];
         for r#type in &self.types {
-            let mut hasher = DefaultHasher::new();
-            r#type.hash(&mut hasher);
-            hash_values.push(hasher.finish());
+            // Typedefs that refer to themselves are left unexpanded.
+            let mut r#type = r#type.expand(resolver).unwrap_or_else(|_| r#type.clone());
+            if r#type.strip_annotations() {
+                nullable += 1;
+            }
+            match r#type {
+                Type::Union(union) => nullable += union.flatten(resolver, types),
+                r#type if !types.contains(&r#type) => types.push(r#type),
+                _ => {}
+            }
         }
 
-        hash_values.sort_unstable();
-        hash_values.hash(state);
-    }
-}
-
-impl PartialEq for FrozenArrayType {
-    fn eq(&self, other: &Self) -> bool {
-        self.r#type == other.r#type
+        nullable
     }
 }
 
-impl Eq for FrozenArrayType {}
+// Resolver for when typedefs shouldn't be expanded.
+struct NoTypedefs;
 
-impl Hash for FrozenArrayType {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.r#type.hash(state);
+impl TypedefResolver for NoTypedefs {
+    fn typedef(&self, _: &str) -> Option<&Typedef> {
+        None
     }
 }
 
-impl PartialEq for ObservableArrayType {
-    fn eq(&self, other: &Self) -> bool {
-        self.r#type == other.r#type
+/* Trait implementations */
+
+impl From<String> for Type {
+    fn from(identifier: String) -> Self {
+        Self::Standard(StandardType {
+            ext_attrs: vec![],
+            name: StandardTypeName::Identifier(identifier),
+            nullable: false,
+            span: None,
+        })
     }
 }
 
-impl Eq for ObservableArrayType {}
-
-impl Hash for ObservableArrayType {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.r#type.hash(state);
+impl From<&str> for Type {
+    fn from(identifier: &str) -> Self {
+        identifier.to_string().into()
     }
 }
 
-impl PartialEq for StandardType {
-    fn eq(&self, other: &Self) -> bool {
-        self.name == other.name
+impl From<PrimitiveType> for Type {
+    fn from(primitive: PrimitiveType) -> Self {
+        Self::Standard(StandardType {
+            ext_attrs: vec![],
+            name: StandardTypeName::Primitive(primitive),
+            nullable: false,
+            span: None,
+        })
     }
 }
 
-impl Eq for StandardType {}
-
-impl Hash for StandardType {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.name.hash(state);
-    }
+impl_eq_without_span! {
+    SequenceType { r#type, nullable }
+    UnionType { ext_attrs, types, nullable }
+    RecordType { key, value }
+    PromiseType { r#type, nullable }
+    FrozenArrayType { r#type, nullable }
+    ObservableArrayType { r#type, nullable }
+    StandardType { ext_attrs, name, nullable }
 }
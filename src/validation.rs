@@ -0,0 +1,590 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::{
+    Argument, AttrSpecial, Attribute, Definition, Member, OpSpecial, Operation, OverloadSet, Span,
+    StandardTypeName, SymbolKind, SymbolTable, Type,
+};
+
+/// Problem found by [`validate`](crate::validate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub rule: Rule,
+    pub severity: Severity,
+    pub message: String,
+    /// Identifier of the definition the problem was found in, if it has one.
+    pub definition: Option<String>,
+    /// Identifier of the member the problem was found in, if it has one.
+    pub member: Option<String>,
+    /// Location of the offending node, if it was parsed.
+    pub span: Option<Span>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// The definitions violate the Web IDL specification.
+    Error,
+    /// The definitions are valid, but likely not what was intended.
+    Warning,
+}
+
+/// Rule violated by a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// Two definitions that aren't partial have the same identifier, or a partial definition has
+    /// the identifier of a definition of a different kind.
+    DuplicateDefinition,
+    /// Two members of a definition have the same identifier and aren't overloaded operations.
+    DuplicateMember,
+    /// An enumeration has the same value more than once.
+    DuplicateEnumValue,
+    /// A type refers to an identifier that isn't defined, or doesn't name a type.
+    UndefinedType,
+    /// An interface inherits from something other than an interface, or a dictionary from
+    /// something other than a dictionary.
+    InvalidInheritance,
+    /// An includes statement doesn't refer to an interface and an interface mixin.
+    InvalidIncludes,
+    /// A member isn't allowed in the definition it is declared in.
+    InvalidMember,
+    /// A partial definition extends a definition that doesn't exist.
+    MissingDefinition,
+    /// An interface or dictionary inherits from itself, directly or through its ancestors.
+    InheritanceCycle,
+    /// A union type includes a nullable type more than once, by being nullable itself or through
+    /// its member types.
+    NullableUnion,
+    /// Overloaded operations or constructors that take the same number of arguments can't be
+    /// told apart by their argument types.
+    AmbiguousOverload,
+    /// An enumeration value isn't all lowercase, with words separated by dashes if at all, as the
+    /// specification strongly suggests.
+    EnumValueCase,
+}
+
+// Where a diagnostic is reported.
+#[derive(Clone, Copy)]
+struct Location<'a> {
+    definition: Option<&'a str>,
+    member: Option<&'a str>,
+    span: Option<&'a Span>,
+}
+
+struct Validator<'a> {
+    symbols: SymbolTable<'a>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/* Functionality implementations */
+
+impl Rule {
+    /// Stable code of the rule, for example to filter diagnostics by.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Rule::DuplicateDefinition => "duplicate-definition",
+            Rule::DuplicateMember => "duplicate-member",
+            Rule::DuplicateEnumValue => "duplicate-enum-value",
+            Rule::UndefinedType => "undefined-type",
+            Rule::InvalidInheritance => "invalid-inheritance",
+            Rule::InvalidIncludes => "invalid-includes",
+            Rule::InvalidMember => "invalid-member",
+            Rule::MissingDefinition => "missing-definition",
+            Rule::InheritanceCycle => "inheritance-cycle",
+            Rule::NullableUnion => "nullable-union",
+            Rule::AmbiguousOverload => "ambiguous-overload",
+            Rule::EnumValueCase => "enum-value-case",
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            Rule::EnumValueCase => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl<'a> Location<'a> {
+    fn definition(definition: &'a Definition) -> Self {
+        Location {
+            // Includes statements are reported on the interface that includes the mixin.
+            definition: match definition {
+                Definition::Includes(includes) => Some(&includes.interface),
+                definition => definition.get_identifier(),
+            },
+            member: None,
+            span: definition.get_span(),
+        }
+    }
+
+    fn member(self, member: Option<&'a str>, span: Option<&'a Span>) -> Self {
+        Location {
+            member,
+            span,
+            ..self
+        }
+    }
+}
+
+pub(crate) fn validate(definitions: &[Definition]) -> Vec<Diagnostic> {
+    let mut validator = Validator {
+        symbols: SymbolTable::new(definitions),
+        diagnostics: vec![],
+    };
+
+    let mut defined = HashSet::new();
+    for definition in definitions {
+        validator.check_duplicate_definition(definition, &mut defined);
+    }
+    for definition in definitions {
+        validator.check_definition(definition);
+    }
+
+    validator.diagnostics
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, rule: Rule, location: Location, message: String) {
+        self.diagnostics.push(Diagnostic {
+            rule,
+            severity: rule.severity(),
+            message,
+            definition: location.definition.map(str::to_string),
+            member: location.member.map(str::to_string),
+            span: location.span.cloned(),
+        });
+    }
+
+    // Reports definitions that aren't partial if there already is one with the same identifier,
+    // as well as partial definitions of a different kind than the one they extend, or that don't
+    // extend anything.
+    fn check_duplicate_definition(
+        &mut self,
+        definition: &'a Definition,
+        defined: &mut HashSet<&'a str>,
+    ) {
+        let Some(identifier) = definition.get_identifier() else {
+            return;
+        };
+
+        let (rule, message) = if !definition.is_partial() {
+            if defined.insert(identifier) {
+                return;
+            }
+            (Rule::DuplicateDefinition, "is already defined")
+        } else {
+            match self.symbols.get(identifier) {
+                Some(symbol) if Some(symbol.kind) != SymbolKind::of(definition) => {
+                    (Rule::DuplicateDefinition, "is already defined")
+                }
+                Some(symbol) if symbol.definition.is_none() => {
+                    (Rule::MissingDefinition, "is only defined as partial")
+                }
+                _ => return,
+            }
+        };
+        self.report(
+            rule,
+            Location::definition(definition),
+            format!("`{}` {}", identifier, message),
+        );
+    }
+
+    fn check_definition(&mut self, definition: &'a Definition) {
+        let location = Location::definition(definition);
+
+        match definition {
+            Definition::Interface(interface) => {
+                if let Some(inheritance) = &interface.inheritance {
+                    if !matches!(self.lookup(inheritance), Some(SymbolKind::Interface)) {
+                        self.report(
+                            Rule::InvalidInheritance,
+                            location,
+                            format!("`{}` is not an interface", inheritance),
+                        );
+                    }
+                    self.check_inheritance_cycle(&interface.identifier, location);
+                }
+                self.check_members(definition, &interface.members, location);
+            }
+            Definition::InterfaceMixin(interface_mixin) => {
+                self.check_members(definition, &interface_mixin.members, location);
+            }
+            Definition::Includes(includes) => {
+                if !matches!(
+                    self.lookup(&includes.interface),
+                    Some(SymbolKind::Interface)
+                ) {
+                    self.report(
+                        Rule::InvalidIncludes,
+                        location,
+                        format!("`{}` is not an interface", includes.interface),
+                    );
+                }
+                if !matches!(
+                    self.lookup(&includes.mixin),
+                    Some(SymbolKind::InterfaceMixin)
+                ) {
+                    self.report(
+                        Rule::InvalidIncludes,
+                        location,
+                        format!("`{}` is not an interface mixin", includes.mixin),
+                    );
+                }
+            }
+            Definition::CallbackInterface(cb_interface) => {
+                self.check_members(definition, &cb_interface.members, location);
+            }
+            Definition::Namespace(namespace) => {
+                self.check_members(definition, &namespace.members, location);
+            }
+            Definition::Dictionary(dictionary) => {
+                if let Some(inheritance) = &dictionary.inheritance {
+                    if !matches!(self.lookup(inheritance), Some(SymbolKind::Dictionary)) {
+                        self.report(
+                            Rule::InvalidInheritance,
+                            location,
+                            format!("`{}` is not a dictionary", inheritance),
+                        );
+                    }
+                    self.check_inheritance_cycle(&dictionary.identifier, location);
+                }
+
+                let mut identifiers = HashSet::new();
+                for member in &dictionary.members {
+                    let location = location.member(Some(&member.identifier), member.span.as_ref());
+                    if !identifiers.insert(member.identifier.as_str()) {
+                        self.report(
+                            Rule::DuplicateMember,
+                            location,
+                            format!("`{}` is already declared", member.identifier),
+                        );
+                    }
+                    self.check_type(&member.r#type, location);
+                }
+            }
+            Definition::Enumeration(r#enum) => {
+                let mut values = HashSet::new();
+                for value in &r#enum.values {
+                    if !values.insert(value) {
+                        self.report(
+                            Rule::DuplicateEnumValue,
+                            location,
+                            format!("{} is already a value of the enumeration", value),
+                        );
+                    }
+                    let lowercase = value
+                        .as_str()
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+                    if !lowercase {
+                        self.report(
+                            Rule::EnumValueCase,
+                            location,
+                            format!(
+                                "{} should be lowercase, with words separated by dashes",
+                                value
+                            ),
+                        );
+                    }
+                }
+            }
+            Definition::CallbackFunction(cb_function) => {
+                self.check_type(&cb_function.r#type, location);
+                self.check_arguments(&cb_function.arguments, location);
+            }
+            Definition::Typedef(typedef) => self.check_type(&typedef.r#type, location),
+        }
+    }
+
+    fn check_members(
+        &mut self,
+        definition: &'a Definition,
+        members: &'a [Member],
+        location: Location<'a>,
+    ) {
+        // Operations may be overloaded, but not share their identifier with other members.
+        let mut identifiers: HashMap<&str, bool> = HashMap::new();
+        let mut declarations = 0;
+        let mut stringifiers = 0;
+
+        for member in members {
+            let location = location.member(member.get_identifier(), member.get_span());
+
+            if let Some(identifier) = member.get_identifier().filter(|i| !i.is_empty()) {
+                let operation = matches!(member, Member::Operation(_));
+                match identifiers.insert(identifier, operation) {
+                    Some(previous) if !(previous && operation) => self.report(
+                        Rule::DuplicateMember,
+                        location,
+                        format!("`{}` is already declared", identifier),
+                    ),
+                    _ => {}
+                }
+            }
+
+            if let Some(reason) = invalid_member(definition, member) {
+                self.report(Rule::InvalidMember, location, reason.to_string());
+            }
+
+            match member {
+                Member::Iterable(_) | Member::Maplike(_) | Member::Setlike(_) => {
+                    declarations += 1;
+                    if declarations == 2 {
+                        self.report(
+                            Rule::InvalidMember,
+                            location,
+                            "an interface can only have one iterable, maplike or setlike \
+                            declaration"
+                                .to_string(),
+                        );
+                    }
+                }
+                Member::Stringifer(_)
+                | Member::Attribute(Attribute {
+                    special: Some(AttrSpecial::Stringifier),
+                    ..
+                }) => {
+                    stringifiers += 1;
+                    if stringifiers == 2 {
+                        self.report(
+                            Rule::InvalidMember,
+                            location,
+                            "an interface can only have one stringifier".to_string(),
+                        );
+                    }
+                }
+                _ => {}
+            }
+
+            match member {
+                Member::Constant(constant) => self.check_type(&constant.r#type, location),
+                Member::Attribute(attribute) => self.check_type(&attribute.r#type, location),
+                Member::Operation(operation) => {
+                    self.check_type(&operation.r#type, location);
+                    self.check_arguments(&operation.arguments, location);
+                }
+                Member::Constructor(constructor) => {
+                    self.check_arguments(&constructor.arguments, location)
+                }
+                Member::Stringifer(_) => {}
+                Member::Iterable(iterable) => {
+                    if let Some(key_type) = &iterable.key_type {
+                        self.check_type(key_type, location);
+                    }
+                    self.check_type(&iterable.value_type, location);
+                    if let Some(arguments) = &iterable.arguments {
+                        self.check_arguments(arguments, location);
+                    }
+                }
+                Member::Maplike(maplike) => {
+                    self.check_type(&maplike.key_type, location);
+                    self.check_type(&maplike.value_type, location);
+                }
+                Member::Setlike(setlike) => self.check_type(&setlike.r#type, location),
+            }
+        }
+
+        self.check_overloads(members, location);
+    }
+
+    // Reports overloaded operations and constructors that can't be told apart by their arguments.
+    fn check_overloads(&mut self, members: &'a [Member], location: Location<'a>) {
+        let mut overload_sets = vec![(None, OverloadSet::constructors(members))];
+        let mut identifiers = HashSet::new();
+        for member in members {
+            if let Member::Operation(operation) = member {
+                let identifier = operation.identifier.as_str();
+                if !identifier.is_empty() && identifiers.insert(identifier) {
+                    overload_sets.push((
+                        Some(identifier),
+                        OverloadSet::operations(members, identifier),
+                    ));
+                }
+            }
+        }
+
+        for (identifier, overload_set) in overload_sets {
+            let Some(first) = overload_set.entries.first() else {
+                continue;
+            };
+            let location = location.member(identifier, first.callable.span());
+            let name = identifier.unwrap_or("the constructor");
+
+            for len in overload_set.lens() {
+                let entries: Vec<_> = overload_set.entries_with_len(len).collect();
+                if entries.len() < 2 {
+                    continue;
+                }
+
+                let message = match overload_set.distinguishing_argument_index(len, &self.symbols) {
+                    None => "can't be distinguished",
+                    // The arguments before the distinguishing one must be the same.
+                    Some(index)
+                        if (0..index).any(|i| {
+                            entries.iter().any(|entry| {
+                                entry.types[i] != entries[0].types[i]
+                                    || entry.optionality[i] != entries[0].optionality[i]
+                            })
+                        }) =>
+                    {
+                        "differ before the distinguishing argument"
+                    }
+                    Some(_) => continue,
+                };
+                self.report(
+                    Rule::AmbiguousOverload,
+                    location,
+                    format!("overloads of `{}` with {} arguments {}", name, len, message),
+                );
+            }
+        }
+    }
+
+    // Reports the cycle only for the definitions that are part of it, not for those that merely
+    // inherit from one.
+    fn check_inheritance_cycle(&mut self, identifier: &str, location: Location<'a>) {
+        if let Err(cycle) = self.symbols.ancestors(identifier) {
+            if cycle.identifiers[0] == identifier {
+                self.report(Rule::InheritanceCycle, location, cycle.to_string());
+            }
+        }
+    }
+
+    fn check_arguments(&mut self, arguments: &'a [Argument], location: Location<'a>) {
+        for argument in arguments {
+            self.check_type(&argument.r#type, location);
+        }
+    }
+
+    fn check_type(&mut self, r#type: &'a Type, location: Location<'a>) {
+        match r#type {
+            Type::Sequence(sequence) => self.check_type(&sequence.r#type, location),
+            Type::Record(record) => self.check_type(&record.value, location),
+            Type::Promise(promise) => self.check_type(&promise.r#type, location),
+            Type::Union(union) => {
+                let message = match union.nullable_member_types_with(&self.symbols) {
+                    0 => None,
+                    1 if !union.nullable => None,
+                    1 => Some("nullable union has a nullable member type"),
+                    _ => Some("union has more than one nullable member type"),
+                };
+                if let Some(message) = message {
+                    self.report(Rule::NullableUnion, location, message.to_string());
+                }
+
+                for r#type in &union.types {
+                    self.check_type(r#type, location);
+                }
+            }
+            Type::FrozenArray(frozen_array) => self.check_type(&frozen_array.r#type, location),
+            Type::ObservableArray(observable_array) => {
+                self.check_type(&observable_array.r#type, location)
+            }
+            Type::Standard(standard) => {
+                let StandardTypeName::Identifier(identifier) = &standard.name else {
+                    return;
+                };
+                let message = match self.lookup(identifier) {
+                    None => format!("`{}` is not defined", identifier),
+                    Some(kind) if !kind.is_type() => format!("`{}` is not a type", identifier),
+                    Some(_) => return,
+                };
+                self.report(Rule::UndefinedType, location, message);
+            }
+        }
+    }
+
+    fn lookup(&self, identifier: &str) -> Option<SymbolKind> {
+        self.symbols.get(identifier).map(|symbol| symbol.kind)
+    }
+}
+
+// Returns why `member` isn't allowed in `definition`, if it isn't.
+fn invalid_member(definition: &Definition, member: &Member) -> Option<&'static str> {
+    let special_operation = matches!(
+        member,
+        Member::Operation(Operation {
+            special: Some(OpSpecial::Getter | OpSpecial::Setter | OpSpecial::Deleter),
+            ..
+        })
+    );
+    let static_member = matches!(
+        member,
+        Member::Operation(Operation {
+            special: Some(OpSpecial::Static),
+            ..
+        }) | Member::Attribute(Attribute {
+            special: Some(AttrSpecial::Static),
+            ..
+        })
+    );
+
+    match (definition, member) {
+        (Definition::Interface(_), _) => None,
+        (Definition::InterfaceMixin(_), Member::Constructor(_)) => {
+            Some("interface mixins can't have constructors")
+        }
+        (
+            Definition::InterfaceMixin(_),
+            Member::Iterable(_) | Member::Maplike(_) | Member::Setlike(_),
+        ) => Some("interface mixins can't have iterable, maplike or setlike declarations"),
+        (Definition::InterfaceMixin(_), _) if static_member => {
+            Some("interface mixins can't have static members")
+        }
+        (Definition::InterfaceMixin(_), _) if special_operation => {
+            Some("interface mixins can't have special operations")
+        }
+        (
+            Definition::InterfaceMixin(_),
+            Member::Attribute(Attribute {
+                special: Some(AttrSpecial::Inherit),
+                ..
+            }),
+        ) => Some("interface mixins can't have inherited attributes"),
+        (Definition::CallbackInterface(_) | Definition::Namespace(_), Member::Constant(_)) => None,
+        (Definition::CallbackInterface(_) | Definition::Namespace(_), Member::Operation(_))
+            if !special_operation && !static_member =>
+        {
+            None
+        }
+        (
+            Definition::Namespace(_),
+            Member::Attribute(Attribute {
+                readonly: true,
+                special: None,
+                ..
+            }),
+        ) => None,
+        (Definition::Namespace(_), Member::Attribute(_)) => {
+            Some("namespaces can only have regular read only attributes")
+        }
+        (Definition::CallbackInterface(_), _) => {
+            Some("callback interfaces can only have constants and regular operations")
+        }
+        (Definition::Namespace(_), _) => {
+            Some("namespaces can only have constants, regular operations and read only attributes")
+        }
+        _ => None,
+    }
+}
+
+/* Trait implementations */
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}[{}]: {}", severity, self.rule.code(), self.message)?;
+
+        match (&self.definition, &self.member) {
+            (Some(definition), Some(member)) => write!(f, " (in `{}.{}`)", definition, member),
+            (Some(definition), None) => write!(f, " (in `{}`)", definition),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,645 @@
+use crate::{
+    Argument, AttrSpecial, Attribute, CallbackFunction, CallbackInterface, ConstValue, Constant,
+    Constructor, DecimalLiteral, DefaultValue, Definition, Dictionary, DictionaryMember,
+    Enumeration, ExtAttrValue, ExtendedAttribute, FrozenArrayType, Includes, IntegerLiteral,
+    Interface, InterfaceMixin, Iterable, Maplike, Member, NamedArgumentList, Namespace,
+    ObservableArrayType, OpSpecial, Operation, PrimitiveType, PromiseType, RecordType,
+    RecordTypeKey, SequenceType, Setlike, Span, StandardType, StandardTypeName, StringLiteral,
+    Stringifer, Type, Typedef, UnionType,
+};
+
+/// Traversal of the definitions by shared reference.
+///
+/// Every method visits the nodes its node is made of, in the order they appear in the source,
+/// followed by the span of its node, if it has one. It does so by calling the free function of the
+/// same name in this module. Override the methods of the nodes you care about, and call that
+/// function from the override to keep walking into the node:
+///
+/// ```
+/// use webidl_rs::{visit::{self, Visit}, Operation};
+///
+/// struct Operations<'ast>(Vec<&'ast str>);
+///
+/// impl<'ast> Visit<'ast> for Operations<'ast> {
+///     fn visit_operation(&mut self, node: &'ast Operation) {
+///         self.0.push(&node.identifier);
+///         visit::visit_operation(self, node);
+///     }
+/// }
+///
+/// let definitions = webidl_rs::parse("interface A { undefined f(); }; namespace B { long g(); };").unwrap();
+/// let mut operations = Operations(vec![]);
+/// for definition in &definitions {
+///     operations.visit_definition(definition);
+/// }
+/// assert!(operations.0 == ["f", "g"]);
+/// ```
+pub trait Visit<'ast> {
+    fn visit_definition(&mut self, node: &'ast Definition) {
+        visit_definition(self, node);
+    }
+
+    fn visit_interface(&mut self, node: &'ast Interface) {
+        visit_interface(self, node);
+    }
+
+    fn visit_interface_mixin(&mut self, node: &'ast InterfaceMixin) {
+        visit_interface_mixin(self, node);
+    }
+
+    fn visit_includes(&mut self, node: &'ast Includes) {
+        visit_includes(self, node);
+    }
+
+    fn visit_callback_interface(&mut self, node: &'ast CallbackInterface) {
+        visit_callback_interface(self, node);
+    }
+
+    fn visit_namespace(&mut self, node: &'ast Namespace) {
+        visit_namespace(self, node);
+    }
+
+    fn visit_dictionary(&mut self, node: &'ast Dictionary) {
+        visit_dictionary(self, node);
+    }
+
+    fn visit_enumeration(&mut self, node: &'ast Enumeration) {
+        visit_enumeration(self, node);
+    }
+
+    fn visit_callback_function(&mut self, node: &'ast CallbackFunction) {
+        visit_callback_function(self, node);
+    }
+
+    fn visit_typedef(&mut self, node: &'ast Typedef) {
+        visit_typedef(self, node);
+    }
+
+    fn visit_dictionary_member(&mut self, node: &'ast DictionaryMember) {
+        visit_dictionary_member(self, node);
+    }
+
+    fn visit_extended_attribute(&mut self, node: &'ast ExtendedAttribute) {
+        visit_extended_attribute(self, node);
+    }
+
+    fn visit_ext_attr_value(&mut self, node: &'ast ExtAttrValue) {
+        visit_ext_attr_value(self, node);
+    }
+
+    fn visit_named_argument_list(&mut self, node: &'ast NamedArgumentList) {
+        visit_named_argument_list(self, node);
+    }
+
+    fn visit_argument(&mut self, node: &'ast Argument) {
+        visit_argument(self, node);
+    }
+
+    fn visit_default_value(&mut self, node: &'ast DefaultValue) {
+        visit_default_value(self, node);
+    }
+
+    fn visit_member(&mut self, node: &'ast Member) {
+        visit_member(self, node);
+    }
+
+    fn visit_constant(&mut self, node: &'ast Constant) {
+        visit_constant(self, node);
+    }
+
+    fn visit_const_value(&mut self, node: &'ast ConstValue) {
+        visit_const_value(self, node);
+    }
+
+    fn visit_attribute(&mut self, node: &'ast Attribute) {
+        visit_attribute(self, node);
+    }
+
+    fn visit_attr_special(&mut self, node: &'ast AttrSpecial) {
+        visit_attr_special(self, node);
+    }
+
+    fn visit_operation(&mut self, node: &'ast Operation) {
+        visit_operation(self, node);
+    }
+
+    fn visit_op_special(&mut self, node: &'ast OpSpecial) {
+        visit_op_special(self, node);
+    }
+
+    fn visit_constructor(&mut self, node: &'ast Constructor) {
+        visit_constructor(self, node);
+    }
+
+    fn visit_stringifer(&mut self, node: &'ast Stringifer) {
+        visit_stringifer(self, node);
+    }
+
+    fn visit_iterable(&mut self, node: &'ast Iterable) {
+        visit_iterable(self, node);
+    }
+
+    fn visit_maplike(&mut self, node: &'ast Maplike) {
+        visit_maplike(self, node);
+    }
+
+    fn visit_setlike(&mut self, node: &'ast Setlike) {
+        visit_setlike(self, node);
+    }
+
+    fn visit_type(&mut self, node: &'ast Type) {
+        visit_type(self, node);
+    }
+
+    fn visit_sequence_type(&mut self, node: &'ast SequenceType) {
+        visit_sequence_type(self, node);
+    }
+
+    fn visit_union_type(&mut self, node: &'ast UnionType) {
+        visit_union_type(self, node);
+    }
+
+    fn visit_record_type(&mut self, node: &'ast RecordType) {
+        visit_record_type(self, node);
+    }
+
+    fn visit_record_type_key(&mut self, node: &'ast RecordTypeKey) {
+        visit_record_type_key(self, node);
+    }
+
+    fn visit_promise_type(&mut self, node: &'ast PromiseType) {
+        visit_promise_type(self, node);
+    }
+
+    fn visit_frozen_array_type(&mut self, node: &'ast FrozenArrayType) {
+        visit_frozen_array_type(self, node);
+    }
+
+    fn visit_observable_array_type(&mut self, node: &'ast ObservableArrayType) {
+        visit_observable_array_type(self, node);
+    }
+
+    fn visit_standard_type(&mut self, node: &'ast StandardType) {
+        visit_standard_type(self, node);
+    }
+
+    fn visit_standard_type_name(&mut self, node: &'ast StandardTypeName) {
+        visit_standard_type_name(self, node);
+    }
+
+    fn visit_primitive_type(&mut self, node: &'ast PrimitiveType) {
+        visit_primitive_type(self, node);
+    }
+
+    fn visit_integer_literal(&mut self, node: &'ast IntegerLiteral) {
+        visit_integer_literal(self, node);
+    }
+
+    fn visit_decimal_literal(&mut self, node: &'ast DecimalLiteral) {
+        visit_decimal_literal(self, node);
+    }
+
+    fn visit_string_literal(&mut self, node: &'ast StringLiteral) {
+        visit_string_literal(self, node);
+    }
+
+    fn visit_span(&mut self, node: &'ast Span) {
+        visit_span(self, node);
+    }
+}
+
+pub fn visit_definition<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Definition) {
+    match node {
+        Definition::Interface(node) => v.visit_interface(node),
+        Definition::InterfaceMixin(node) => v.visit_interface_mixin(node),
+        Definition::Includes(node) => v.visit_includes(node),
+        Definition::CallbackInterface(node) => v.visit_callback_interface(node),
+        Definition::Namespace(node) => v.visit_namespace(node),
+        Definition::Dictionary(node) => v.visit_dictionary(node),
+        Definition::Enumeration(node) => v.visit_enumeration(node),
+        Definition::CallbackFunction(node) => v.visit_callback_function(node),
+        Definition::Typedef(node) => v.visit_typedef(node),
+    }
+}
+
+pub fn visit_interface<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Interface) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    for node in &node.members {
+        v.visit_member(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_interface_mixin<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast InterfaceMixin) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    for node in &node.members {
+        v.visit_member(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_includes<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Includes) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_callback_interface<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CallbackInterface,
+) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    for node in &node.members {
+        v.visit_member(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_namespace<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Namespace) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    for node in &node.members {
+        v.visit_member(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_dictionary<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Dictionary) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    for node in &node.members {
+        v.visit_dictionary_member(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_enumeration<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Enumeration) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    for node in &node.values {
+        v.visit_string_literal(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_callback_function<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CallbackFunction,
+) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    v.visit_type(&node.r#type);
+    for node in &node.arguments {
+        v.visit_argument(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_typedef<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Typedef) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    v.visit_type(&node.r#type);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_dictionary_member<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast DictionaryMember,
+) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    v.visit_type(&node.r#type);
+    if let Some(node) = &node.default {
+        v.visit_default_value(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_extended_attribute<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ExtendedAttribute,
+) {
+    if let Some(node) = &node.value {
+        v.visit_ext_attr_value(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_ext_attr_value<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExtAttrValue) {
+    match node {
+        ExtAttrValue::ArgumentList(nodes) => {
+            for node in nodes {
+                v.visit_argument(node);
+            }
+        }
+        ExtAttrValue::NamedArgumentList(node) => v.visit_named_argument_list(node),
+        ExtAttrValue::String(node) => v.visit_string_literal(node),
+        ExtAttrValue::StringList(nodes) => {
+            for node in nodes {
+                v.visit_string_literal(node);
+            }
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_named_argument_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast NamedArgumentList,
+) {
+    for node in &node.arguments {
+        v.visit_argument(node);
+    }
+}
+
+pub fn visit_argument<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Argument) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    v.visit_type(&node.r#type);
+    if let Some(node) = &node.default {
+        v.visit_default_value(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_default_value<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DefaultValue) {
+    match node {
+        DefaultValue::Integer(node) => v.visit_integer_literal(node),
+        DefaultValue::Decimal(node) => v.visit_decimal_literal(node),
+        DefaultValue::String(node) => v.visit_string_literal(node),
+        _ => {}
+    }
+}
+
+pub fn visit_member<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Member) {
+    match node {
+        Member::Constant(node) => v.visit_constant(node),
+        Member::Attribute(node) => v.visit_attribute(node),
+        Member::Operation(node) => v.visit_operation(node),
+        Member::Constructor(node) => v.visit_constructor(node),
+        Member::Stringifer(node) => v.visit_stringifer(node),
+        Member::Iterable(node) => v.visit_iterable(node),
+        Member::Maplike(node) => v.visit_maplike(node),
+        Member::Setlike(node) => v.visit_setlike(node),
+    }
+}
+
+pub fn visit_constant<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Constant) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    v.visit_type(&node.r#type);
+    v.visit_const_value(&node.value);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_const_value<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ConstValue) {
+    match node {
+        ConstValue::Integer(node) => v.visit_integer_literal(node),
+        ConstValue::Decimal(node) => v.visit_decimal_literal(node),
+        _ => {}
+    }
+}
+
+pub fn visit_attribute<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Attribute) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    if let Some(node) = &node.special {
+        v.visit_attr_special(node);
+    }
+    v.visit_type(&node.r#type);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_attr_special<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast AttrSpecial) {}
+
+pub fn visit_operation<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Operation) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    if let Some(node) = &node.special {
+        v.visit_op_special(node);
+    }
+    v.visit_type(&node.r#type);
+    for node in &node.arguments {
+        v.visit_argument(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_op_special<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast OpSpecial) {}
+
+pub fn visit_constructor<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Constructor) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    for node in &node.arguments {
+        v.visit_argument(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_stringifer<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Stringifer) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_iterable<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Iterable) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    if let Some(node) = &node.key_type {
+        v.visit_type(node);
+    }
+    v.visit_type(&node.value_type);
+    for node in node.arguments.iter().flatten() {
+        v.visit_argument(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_maplike<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Maplike) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    v.visit_type(&node.key_type);
+    v.visit_type(&node.value_type);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_setlike<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Setlike) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    v.visit_type(&node.r#type);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Type) {
+    match node {
+        Type::Sequence(node) => v.visit_sequence_type(node),
+        Type::Record(node) => v.visit_record_type(node),
+        Type::Promise(node) => v.visit_promise_type(node),
+        Type::Union(node) => v.visit_union_type(node),
+        Type::FrozenArray(node) => v.visit_frozen_array_type(node),
+        Type::ObservableArray(node) => v.visit_observable_array_type(node),
+        Type::Standard(node) => v.visit_standard_type(node),
+    }
+}
+
+pub fn visit_sequence_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SequenceType) {
+    v.visit_type(&node.r#type);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_union_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast UnionType) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    for node in &node.types {
+        v.visit_type(node);
+    }
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_record_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RecordType) {
+    v.visit_record_type_key(&node.key);
+    v.visit_type(&node.value);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_record_type_key<'ast, V: Visit<'ast> + ?Sized>(
+    _v: &mut V,
+    _node: &'ast RecordTypeKey,
+) {
+}
+
+pub fn visit_promise_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PromiseType) {
+    v.visit_type(&node.r#type);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_frozen_array_type<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast FrozenArrayType,
+) {
+    v.visit_type(&node.r#type);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_observable_array_type<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ObservableArrayType,
+) {
+    v.visit_type(&node.r#type);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_standard_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast StandardType) {
+    for node in &node.ext_attrs {
+        v.visit_extended_attribute(node);
+    }
+    v.visit_standard_type_name(&node.name);
+    if let Some(node) = &node.span {
+        v.visit_span(node);
+    }
+}
+
+pub fn visit_standard_type_name<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast StandardTypeName,
+) {
+    match node {
+        StandardTypeName::Primitive(node) => v.visit_primitive_type(node),
+        StandardTypeName::Identifier(_) => {}
+    }
+}
+
+pub fn visit_primitive_type<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast PrimitiveType) {
+}
+
+pub fn visit_integer_literal<'ast, V: Visit<'ast> + ?Sized>(
+    _v: &mut V,
+    _node: &'ast IntegerLiteral,
+) {
+}
+
+pub fn visit_decimal_literal<'ast, V: Visit<'ast> + ?Sized>(
+    _v: &mut V,
+    _node: &'ast DecimalLiteral,
+) {
+}
+
+pub fn visit_string_literal<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast StringLiteral) {
+}
+
+pub fn visit_span<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Span) {}
@@ -0,0 +1,613 @@
+use crate::{
+    Argument, AttrSpecial, Attribute, CallbackFunction, CallbackInterface, ConstValue, Constant,
+    Constructor, DecimalLiteral, DefaultValue, Definition, Dictionary, DictionaryMember,
+    Enumeration, ExtAttrValue, ExtendedAttribute, FrozenArrayType, Includes, IntegerLiteral,
+    Interface, InterfaceMixin, Iterable, Maplike, Member, NamedArgumentList, Namespace,
+    ObservableArrayType, OpSpecial, Operation, PrimitiveType, PromiseType, RecordType,
+    RecordTypeKey, SequenceType, Setlike, Span, StandardType, StandardTypeName, StringLiteral,
+    Stringifer, Type, Typedef, UnionType,
+};
+
+/// Traversal of the definitions by mutable reference, to rewrite them in place.
+///
+/// Like [`Visit`](crate::visit::Visit), every method visits the nodes its node is made of by
+/// calling the free function of the same name in this module:
+///
+/// ```
+/// use webidl_rs::{visit_mut::{self, VisitMut}, PrimitiveType, StandardType, StandardTypeName};
+///
+/// // Replaces `DOMString` with `USVString`, wherever it's used.
+/// struct UseUsvString;
+///
+/// impl VisitMut for UseUsvString {
+///     fn visit_standard_type_mut(&mut self, node: &mut StandardType) {
+///         if node.name == StandardTypeName::Primitive(PrimitiveType::DOMString) {
+///             node.name = StandardTypeName::Primitive(PrimitiveType::USVString);
+///         }
+///         visit_mut::visit_standard_type_mut(self, node);
+///     }
+/// }
+///
+/// let mut definitions = webidl_rs::parse("typedef sequence<DOMString> Names;").unwrap();
+/// for definition in &mut definitions {
+///     UseUsvString.visit_definition_mut(definition);
+/// }
+/// assert!(webidl_rs::to_string(&definitions) == "typedef sequence<USVString> Names;");
+/// ```
+pub trait VisitMut {
+    fn visit_definition_mut(&mut self, node: &mut Definition) {
+        visit_definition_mut(self, node);
+    }
+
+    fn visit_interface_mut(&mut self, node: &mut Interface) {
+        visit_interface_mut(self, node);
+    }
+
+    fn visit_interface_mixin_mut(&mut self, node: &mut InterfaceMixin) {
+        visit_interface_mixin_mut(self, node);
+    }
+
+    fn visit_includes_mut(&mut self, node: &mut Includes) {
+        visit_includes_mut(self, node);
+    }
+
+    fn visit_callback_interface_mut(&mut self, node: &mut CallbackInterface) {
+        visit_callback_interface_mut(self, node);
+    }
+
+    fn visit_namespace_mut(&mut self, node: &mut Namespace) {
+        visit_namespace_mut(self, node);
+    }
+
+    fn visit_dictionary_mut(&mut self, node: &mut Dictionary) {
+        visit_dictionary_mut(self, node);
+    }
+
+    fn visit_enumeration_mut(&mut self, node: &mut Enumeration) {
+        visit_enumeration_mut(self, node);
+    }
+
+    fn visit_callback_function_mut(&mut self, node: &mut CallbackFunction) {
+        visit_callback_function_mut(self, node);
+    }
+
+    fn visit_typedef_mut(&mut self, node: &mut Typedef) {
+        visit_typedef_mut(self, node);
+    }
+
+    fn visit_dictionary_member_mut(&mut self, node: &mut DictionaryMember) {
+        visit_dictionary_member_mut(self, node);
+    }
+
+    fn visit_extended_attribute_mut(&mut self, node: &mut ExtendedAttribute) {
+        visit_extended_attribute_mut(self, node);
+    }
+
+    fn visit_ext_attr_value_mut(&mut self, node: &mut ExtAttrValue) {
+        visit_ext_attr_value_mut(self, node);
+    }
+
+    fn visit_named_argument_list_mut(&mut self, node: &mut NamedArgumentList) {
+        visit_named_argument_list_mut(self, node);
+    }
+
+    fn visit_argument_mut(&mut self, node: &mut Argument) {
+        visit_argument_mut(self, node);
+    }
+
+    fn visit_default_value_mut(&mut self, node: &mut DefaultValue) {
+        visit_default_value_mut(self, node);
+    }
+
+    fn visit_member_mut(&mut self, node: &mut Member) {
+        visit_member_mut(self, node);
+    }
+
+    fn visit_constant_mut(&mut self, node: &mut Constant) {
+        visit_constant_mut(self, node);
+    }
+
+    fn visit_const_value_mut(&mut self, node: &mut ConstValue) {
+        visit_const_value_mut(self, node);
+    }
+
+    fn visit_attribute_mut(&mut self, node: &mut Attribute) {
+        visit_attribute_mut(self, node);
+    }
+
+    fn visit_attr_special_mut(&mut self, node: &mut AttrSpecial) {
+        visit_attr_special_mut(self, node);
+    }
+
+    fn visit_operation_mut(&mut self, node: &mut Operation) {
+        visit_operation_mut(self, node);
+    }
+
+    fn visit_op_special_mut(&mut self, node: &mut OpSpecial) {
+        visit_op_special_mut(self, node);
+    }
+
+    fn visit_constructor_mut(&mut self, node: &mut Constructor) {
+        visit_constructor_mut(self, node);
+    }
+
+    fn visit_stringifer_mut(&mut self, node: &mut Stringifer) {
+        visit_stringifer_mut(self, node);
+    }
+
+    fn visit_iterable_mut(&mut self, node: &mut Iterable) {
+        visit_iterable_mut(self, node);
+    }
+
+    fn visit_maplike_mut(&mut self, node: &mut Maplike) {
+        visit_maplike_mut(self, node);
+    }
+
+    fn visit_setlike_mut(&mut self, node: &mut Setlike) {
+        visit_setlike_mut(self, node);
+    }
+
+    fn visit_type_mut(&mut self, node: &mut Type) {
+        visit_type_mut(self, node);
+    }
+
+    fn visit_sequence_type_mut(&mut self, node: &mut SequenceType) {
+        visit_sequence_type_mut(self, node);
+    }
+
+    fn visit_union_type_mut(&mut self, node: &mut UnionType) {
+        visit_union_type_mut(self, node);
+    }
+
+    fn visit_record_type_mut(&mut self, node: &mut RecordType) {
+        visit_record_type_mut(self, node);
+    }
+
+    fn visit_record_type_key_mut(&mut self, node: &mut RecordTypeKey) {
+        visit_record_type_key_mut(self, node);
+    }
+
+    fn visit_promise_type_mut(&mut self, node: &mut PromiseType) {
+        visit_promise_type_mut(self, node);
+    }
+
+    fn visit_frozen_array_type_mut(&mut self, node: &mut FrozenArrayType) {
+        visit_frozen_array_type_mut(self, node);
+    }
+
+    fn visit_observable_array_type_mut(&mut self, node: &mut ObservableArrayType) {
+        visit_observable_array_type_mut(self, node);
+    }
+
+    fn visit_standard_type_mut(&mut self, node: &mut StandardType) {
+        visit_standard_type_mut(self, node);
+    }
+
+    fn visit_standard_type_name_mut(&mut self, node: &mut StandardTypeName) {
+        visit_standard_type_name_mut(self, node);
+    }
+
+    fn visit_primitive_type_mut(&mut self, node: &mut PrimitiveType) {
+        visit_primitive_type_mut(self, node);
+    }
+
+    fn visit_integer_literal_mut(&mut self, node: &mut IntegerLiteral) {
+        visit_integer_literal_mut(self, node);
+    }
+
+    fn visit_decimal_literal_mut(&mut self, node: &mut DecimalLiteral) {
+        visit_decimal_literal_mut(self, node);
+    }
+
+    fn visit_string_literal_mut(&mut self, node: &mut StringLiteral) {
+        visit_string_literal_mut(self, node);
+    }
+
+    fn visit_span_mut(&mut self, node: &mut Span) {
+        visit_span_mut(self, node);
+    }
+}
+
+pub fn visit_definition_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Definition) {
+    match node {
+        Definition::Interface(node) => v.visit_interface_mut(node),
+        Definition::InterfaceMixin(node) => v.visit_interface_mixin_mut(node),
+        Definition::Includes(node) => v.visit_includes_mut(node),
+        Definition::CallbackInterface(node) => v.visit_callback_interface_mut(node),
+        Definition::Namespace(node) => v.visit_namespace_mut(node),
+        Definition::Dictionary(node) => v.visit_dictionary_mut(node),
+        Definition::Enumeration(node) => v.visit_enumeration_mut(node),
+        Definition::CallbackFunction(node) => v.visit_callback_function_mut(node),
+        Definition::Typedef(node) => v.visit_typedef_mut(node),
+    }
+}
+
+pub fn visit_interface_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Interface) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    for node in &mut node.members {
+        v.visit_member_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_interface_mixin_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut InterfaceMixin) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    for node in &mut node.members {
+        v.visit_member_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_includes_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Includes) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_callback_interface_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CallbackInterface) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    for node in &mut node.members {
+        v.visit_member_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_namespace_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Namespace) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    for node in &mut node.members {
+        v.visit_member_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_dictionary_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Dictionary) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    for node in &mut node.members {
+        v.visit_dictionary_member_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_enumeration_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Enumeration) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    for node in &mut node.values {
+        v.visit_string_literal_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_callback_function_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CallbackFunction) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    v.visit_type_mut(&mut node.r#type);
+    for node in &mut node.arguments {
+        v.visit_argument_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_typedef_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Typedef) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    v.visit_type_mut(&mut node.r#type);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_dictionary_member_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DictionaryMember) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    v.visit_type_mut(&mut node.r#type);
+    if let Some(node) = &mut node.default {
+        v.visit_default_value_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_extended_attribute_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExtendedAttribute) {
+    if let Some(node) = &mut node.value {
+        v.visit_ext_attr_value_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_ext_attr_value_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExtAttrValue) {
+    match node {
+        ExtAttrValue::ArgumentList(nodes) => {
+            for node in nodes {
+                v.visit_argument_mut(node);
+            }
+        }
+        ExtAttrValue::NamedArgumentList(node) => v.visit_named_argument_list_mut(node),
+        ExtAttrValue::String(node) => v.visit_string_literal_mut(node),
+        ExtAttrValue::StringList(nodes) => {
+            for node in nodes {
+                v.visit_string_literal_mut(node);
+            }
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_named_argument_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut NamedArgumentList,
+) {
+    for node in &mut node.arguments {
+        v.visit_argument_mut(node);
+    }
+}
+
+pub fn visit_argument_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Argument) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    v.visit_type_mut(&mut node.r#type);
+    if let Some(node) = &mut node.default {
+        v.visit_default_value_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_default_value_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DefaultValue) {
+    match node {
+        DefaultValue::Integer(node) => v.visit_integer_literal_mut(node),
+        DefaultValue::Decimal(node) => v.visit_decimal_literal_mut(node),
+        DefaultValue::String(node) => v.visit_string_literal_mut(node),
+        _ => {}
+    }
+}
+
+pub fn visit_member_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Member) {
+    match node {
+        Member::Constant(node) => v.visit_constant_mut(node),
+        Member::Attribute(node) => v.visit_attribute_mut(node),
+        Member::Operation(node) => v.visit_operation_mut(node),
+        Member::Constructor(node) => v.visit_constructor_mut(node),
+        Member::Stringifer(node) => v.visit_stringifer_mut(node),
+        Member::Iterable(node) => v.visit_iterable_mut(node),
+        Member::Maplike(node) => v.visit_maplike_mut(node),
+        Member::Setlike(node) => v.visit_setlike_mut(node),
+    }
+}
+
+pub fn visit_constant_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Constant) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    v.visit_type_mut(&mut node.r#type);
+    v.visit_const_value_mut(&mut node.value);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_const_value_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ConstValue) {
+    match node {
+        ConstValue::Integer(node) => v.visit_integer_literal_mut(node),
+        ConstValue::Decimal(node) => v.visit_decimal_literal_mut(node),
+        _ => {}
+    }
+}
+
+pub fn visit_attribute_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Attribute) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    if let Some(node) = &mut node.special {
+        v.visit_attr_special_mut(node);
+    }
+    v.visit_type_mut(&mut node.r#type);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_attr_special_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut AttrSpecial) {}
+
+pub fn visit_operation_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Operation) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    if let Some(node) = &mut node.special {
+        v.visit_op_special_mut(node);
+    }
+    v.visit_type_mut(&mut node.r#type);
+    for node in &mut node.arguments {
+        v.visit_argument_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_op_special_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut OpSpecial) {}
+
+pub fn visit_constructor_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Constructor) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    for node in &mut node.arguments {
+        v.visit_argument_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_stringifer_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Stringifer) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_iterable_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Iterable) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    if let Some(node) = &mut node.key_type {
+        v.visit_type_mut(node);
+    }
+    v.visit_type_mut(&mut node.value_type);
+    for node in node.arguments.iter_mut().flatten() {
+        v.visit_argument_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_maplike_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Maplike) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    v.visit_type_mut(&mut node.key_type);
+    v.visit_type_mut(&mut node.value_type);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_setlike_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Setlike) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    v.visit_type_mut(&mut node.r#type);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Type) {
+    match node {
+        Type::Sequence(node) => v.visit_sequence_type_mut(node),
+        Type::Record(node) => v.visit_record_type_mut(node),
+        Type::Promise(node) => v.visit_promise_type_mut(node),
+        Type::Union(node) => v.visit_union_type_mut(node),
+        Type::FrozenArray(node) => v.visit_frozen_array_type_mut(node),
+        Type::ObservableArray(node) => v.visit_observable_array_type_mut(node),
+        Type::Standard(node) => v.visit_standard_type_mut(node),
+    }
+}
+
+pub fn visit_sequence_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SequenceType) {
+    v.visit_type_mut(&mut node.r#type);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_union_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut UnionType) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    for node in &mut node.types {
+        v.visit_type_mut(node);
+    }
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_record_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RecordType) {
+    v.visit_record_type_key_mut(&mut node.key);
+    v.visit_type_mut(&mut node.value);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_record_type_key_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut RecordTypeKey) {}
+
+pub fn visit_promise_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PromiseType) {
+    v.visit_type_mut(&mut node.r#type);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_frozen_array_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut FrozenArrayType) {
+    v.visit_type_mut(&mut node.r#type);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_observable_array_type_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ObservableArrayType,
+) {
+    v.visit_type_mut(&mut node.r#type);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_standard_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut StandardType) {
+    for node in &mut node.ext_attrs {
+        v.visit_extended_attribute_mut(node);
+    }
+    v.visit_standard_type_name_mut(&mut node.name);
+    if let Some(node) = &mut node.span {
+        v.visit_span_mut(node);
+    }
+}
+
+pub fn visit_standard_type_name_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut StandardTypeName) {
+    match node {
+        StandardTypeName::Primitive(node) => v.visit_primitive_type_mut(node),
+        StandardTypeName::Identifier(_) => {}
+    }
+}
+
+pub fn visit_primitive_type_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut PrimitiveType) {}
+
+pub fn visit_integer_literal_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut IntegerLiteral) {}
+
+pub fn visit_decimal_literal_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut DecimalLiteral) {}
+
+pub fn visit_string_literal_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut StringLiteral) {}
+
+pub fn visit_span_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Span) {}
@@ -0,0 +1,955 @@
+use std::fmt;
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    parser, Argument, AttrSpecial, Attribute, CallbackFunction, CallbackInterface, ConstValue,
+    Constant, Constructor, DefaultValue, Definition, Dictionary, DictionaryMember, Enumeration,
+    ExtAttrValue, ExtendedAttribute, FrozenArrayType, Includes, Interface, InterfaceMixin,
+    Iterable, Maplike, Member, NamedArgumentList, Namespace, ObservableArrayType, OpSpecial,
+    Operation, PrimitiveType, PromiseType, RecordType, RecordTypeKey, SequenceType, Setlike,
+    StandardType, StandardTypeName, StringLiteral, Stringifer, Type, Typedef, UnionType,
+};
+
+/// Error of [`from_webidl2_json`](crate::from_webidl2_json): the JSON isn't an AST as produced by
+/// webidl2.js, or it uses something that can't be represented by the definitions of this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Webidl2Error {
+    pub message: String,
+}
+
+// A JSON object of the AST. Accessing its fields fails with a message that names the object.
+struct Node<'a> {
+    kind: &'a str,
+    object: &'a Map<String, Value>,
+}
+
+/* Functionality implementations */
+
+pub(crate) fn to_json(definitions: &[Definition]) -> Value {
+    definitions
+        .iter()
+        .map(definition_to_json)
+        // webidl2.js ends the definitions of a concrete syntax tree with this.
+        .chain([json!({ "type": "eof", "value": "" })])
+        .collect()
+}
+
+pub(crate) fn from_json(json: &Value) -> Result<Vec<Definition>, Webidl2Error> {
+    let definitions = json
+        .as_array()
+        .ok_or_else(|| Webidl2Error::new("expected an array of definitions".to_string()))?;
+
+    definitions
+        .iter()
+        // Only present if webidl2.js was asked for a concrete syntax tree, see `to_json`.
+        .filter(|definition| definition["type"] != "eof")
+        .map(definition_from_json)
+        .collect()
+}
+
+impl Webidl2Error {
+    fn new(message: String) -> Self {
+        Webidl2Error { message }
+    }
+}
+
+/* Export */
+
+fn definition_to_json(definition: &Definition) -> Value {
+    match definition {
+        Definition::Interface(interface) => container_to_json(
+            "interface",
+            &interface.identifier,
+            interface.inheritance.as_deref(),
+            members_to_json(&interface.members),
+            &interface.ext_attrs,
+            interface.partial,
+        ),
+        Definition::InterfaceMixin(mixin) => container_to_json(
+            "interface mixin",
+            &mixin.identifier,
+            None,
+            members_to_json(&mixin.members),
+            &mixin.ext_attrs,
+            mixin.partial,
+        ),
+        Definition::Includes(includes) => json!({
+            "type": "includes",
+            "target": includes.interface,
+            "includes": includes.mixin,
+            "extAttrs": ext_attrs_to_json(&includes.ext_attrs),
+        }),
+        Definition::CallbackInterface(cb_interface) => container_to_json(
+            "callback interface",
+            &cb_interface.identifier,
+            None,
+            members_to_json(&cb_interface.members),
+            &cb_interface.ext_attrs,
+            false,
+        ),
+        Definition::Namespace(namespace) => container_to_json(
+            "namespace",
+            &namespace.identifier,
+            None,
+            members_to_json(&namespace.members),
+            &namespace.ext_attrs,
+            namespace.partial,
+        ),
+        Definition::Dictionary(dictionary) => container_to_json(
+            "dictionary",
+            &dictionary.identifier,
+            dictionary.inheritance.as_deref(),
+            dictionary.members.iter().map(field_to_json).collect(),
+            &dictionary.ext_attrs,
+            dictionary.partial,
+        ),
+        Definition::Enumeration(enumeration) => json!({
+            "type": "enum",
+            "name": enumeration.identifier,
+            "values": enumeration
+                .values
+                .iter()
+                .map(|value| json!({ "type": "enum-value", "value": value.as_str() }))
+                .collect::<Vec<_>>(),
+            "extAttrs": ext_attrs_to_json(&enumeration.ext_attrs),
+        }),
+        Definition::CallbackFunction(cb_function) => json!({
+            "type": "callback",
+            "name": cb_function.identifier,
+            "idlType": type_to_json(&cb_function.r#type, Some("return-type")),
+            "arguments": arguments_to_json(&cb_function.arguments),
+            "extAttrs": ext_attrs_to_json(&cb_function.ext_attrs),
+        }),
+        Definition::Typedef(typedef) => json!({
+            "type": "typedef",
+            "name": typedef.identifier,
+            "idlType": type_to_json(&typedef.r#type, Some("typedef-type")),
+            "extAttrs": ext_attrs_to_json(&typedef.ext_attrs),
+        }),
+    }
+}
+
+fn container_to_json(
+    kind: &str,
+    identifier: &str,
+    inheritance: Option<&str>,
+    members: Vec<Value>,
+    ext_attrs: &[ExtendedAttribute],
+    partial: bool,
+) -> Value {
+    json!({
+        "type": kind,
+        "name": identifier,
+        "inheritance": inheritance,
+        "members": members,
+        "extAttrs": ext_attrs_to_json(ext_attrs),
+        "partial": partial,
+    })
+}
+
+fn members_to_json(members: &[Member]) -> Vec<Value> {
+    members.iter().map(member_to_json).collect()
+}
+
+fn member_to_json(member: &Member) -> Value {
+    match member {
+        Member::Constant(constant) => json!({
+            "type": "const",
+            "name": constant.identifier,
+            "idlType": type_to_json(&constant.r#type, Some("const-type")),
+            "extAttrs": ext_attrs_to_json(&constant.ext_attrs),
+            "value": const_value_to_json(&constant.value),
+        }),
+        Member::Attribute(attribute) => json!({
+            "type": "attribute",
+            "name": attribute.identifier,
+            "idlType": type_to_json(&attribute.r#type, Some("attribute-type")),
+            "extAttrs": ext_attrs_to_json(&attribute.ext_attrs),
+            "special": attribute.special.as_ref().map_or(String::new(), ToString::to_string),
+            "readonly": attribute.readonly,
+        }),
+        Member::Operation(operation) => json!({
+            "type": "operation",
+            "name": operation.identifier,
+            "idlType": type_to_json(&operation.r#type, Some("return-type")),
+            "arguments": arguments_to_json(&operation.arguments),
+            "extAttrs": ext_attrs_to_json(&operation.ext_attrs),
+            "special": operation.special.as_ref().map_or(String::new(), ToString::to_string),
+        }),
+        Member::Constructor(constructor) => json!({
+            "type": "constructor",
+            "arguments": arguments_to_json(&constructor.arguments),
+            "extAttrs": ext_attrs_to_json(&constructor.ext_attrs),
+        }),
+        // webidl2.js treats `stringifier;` as an operation without a name and return type.
+        Member::Stringifer(stringifier) => json!({
+            "type": "operation",
+            "name": "",
+            "idlType": null,
+            "arguments": [],
+            "extAttrs": ext_attrs_to_json(&stringifier.ext_attrs),
+            "special": "stringifier",
+        }),
+        Member::Iterable(iterable) => {
+            let mut types: Vec<_> = iterable.key_type.iter().collect();
+            types.push(&iterable.value_type);
+            declaration_to_json(
+                if iterable.r#async {
+                    "async_iterable"
+                } else {
+                    "iterable"
+                },
+                &types,
+                iterable.arguments.as_deref().unwrap_or_default(),
+                &iterable.ext_attrs,
+                false,
+                iterable.r#async,
+            )
+        }
+        Member::Maplike(maplike) => declaration_to_json(
+            "maplike",
+            &[&maplike.key_type, &maplike.value_type],
+            &[],
+            &maplike.ext_attrs,
+            maplike.readonly,
+            false,
+        ),
+        Member::Setlike(setlike) => declaration_to_json(
+            "setlike",
+            &[&setlike.r#type],
+            &[],
+            &setlike.ext_attrs,
+            setlike.readonly,
+            false,
+        ),
+    }
+}
+
+// Iterable, maplike and setlike declarations.
+fn declaration_to_json(
+    kind: &str,
+    types: &[&Type],
+    arguments: &[Argument],
+    ext_attrs: &[ExtendedAttribute],
+    readonly: bool,
+    r#async: bool,
+) -> Value {
+    json!({
+        "type": kind,
+        "idlType": types
+            .iter()
+            .map(|r#type| type_to_json(r#type, None))
+            .collect::<Vec<_>>(),
+        "arguments": arguments_to_json(arguments),
+        "extAttrs": ext_attrs_to_json(ext_attrs),
+        "readonly": readonly,
+        "async": r#async,
+    })
+}
+
+fn field_to_json(member: &DictionaryMember) -> Value {
+    json!({
+        "type": "field",
+        "name": member.identifier,
+        "extAttrs": ext_attrs_to_json(&member.ext_attrs),
+        "idlType": type_to_json(&member.r#type, Some("dictionary-type")),
+        "default": member.default.as_ref().map(default_value_to_json),
+        "required": member.required,
+    })
+}
+
+fn arguments_to_json(arguments: &[Argument]) -> Vec<Value> {
+    arguments
+        .iter()
+        .map(|argument| {
+            json!({
+                "type": "argument",
+                "name": argument.identifier,
+                "extAttrs": ext_attrs_to_json(&argument.ext_attrs),
+                "idlType": type_to_json(&argument.r#type, Some("argument-type")),
+                "default": argument.default.as_ref().map(default_value_to_json),
+                "optional": argument.optional,
+                "variadic": argument.variadic,
+            })
+        })
+        .collect()
+}
+
+fn ext_attrs_to_json(ext_attrs: &[ExtendedAttribute]) -> Vec<Value> {
+    ext_attrs
+        .iter()
+        .map(|ext_attr| {
+            let list = |kind: &str, values: Vec<String>| {
+                let values: Vec<_> = values
+                    .into_iter()
+                    .map(|value| json!({ "value": value }))
+                    .collect();
+                json!({ "type": kind, "value": values })
+            };
+            let (rhs, arguments) = match &ext_attr.value {
+                None => (Value::Null, &[][..]),
+                Some(ExtAttrValue::ArgumentList(arguments)) => (Value::Null, &arguments[..]),
+                Some(ExtAttrValue::NamedArgumentList(list)) => (
+                    json!({ "type": "identifier", "value": list.identifier }),
+                    &list.arguments[..],
+                ),
+                Some(ExtAttrValue::Identifier(identifier)) => (
+                    json!({ "type": "identifier", "value": identifier }),
+                    &[][..],
+                ),
+                Some(ExtAttrValue::IdentifierList(identifiers)) => {
+                    (list("identifier-list", identifiers.clone()), &[][..])
+                }
+                // Unlike default values, strings of extended attributes keep their quotes.
+                Some(ExtAttrValue::String(string)) => (
+                    json!({ "type": "string", "value": string.to_string() }),
+                    &[][..],
+                ),
+                Some(ExtAttrValue::StringList(strings)) => (
+                    list(
+                        "string-list",
+                        strings.iter().map(ToString::to_string).collect(),
+                    ),
+                    &[][..],
+                ),
+                Some(ExtAttrValue::Wildcard) => (json!({ "type": "*", "value": null }), &[][..]),
+            };
+
+            json!({
+                "type": "extended-attribute",
+                "name": ext_attr.identifier,
+                "rhs": rhs,
+                "arguments": arguments_to_json(arguments),
+            })
+        })
+        .collect()
+}
+
+// `context` is the kind of the construct the type belongs to, such as `"argument-type"`, and is
+// passed on to nested types. webidl2.js leaves it `null` for the types of iterable, maplike and
+// setlike declarations.
+fn type_to_json(r#type: &Type, context: Option<&str>) -> Value {
+    let generic = |generic: &str, types: &[&Type], nullable: bool| {
+        json!({
+            "type": context,
+            "extAttrs": [],
+            "generic": generic,
+            "nullable": nullable,
+            "union": false,
+            "idlType": types
+                .iter()
+                .map(|r#type| type_to_json(r#type, context))
+                .collect::<Vec<_>>(),
+        })
+    };
+
+    match r#type {
+        Type::Sequence(sequence) => generic("sequence", &[&sequence.r#type], sequence.nullable),
+        Type::Record(record) => {
+            let key = Type::from(record.key.to_string());
+            generic("record", &[&key, &record.value], false)
+        }
+        Type::Promise(promise) => generic("Promise", &[&promise.r#type], promise.nullable),
+        Type::FrozenArray(frozen_array) => generic(
+            "FrozenArray",
+            &[&frozen_array.r#type],
+            frozen_array.nullable,
+        ),
+        Type::ObservableArray(observable_array) => generic(
+            "ObservableArray",
+            &[&observable_array.r#type],
+            observable_array.nullable,
+        ),
+        Type::Union(union) => json!({
+            "type": context,
+            "extAttrs": ext_attrs_to_json(&union.ext_attrs),
+            "generic": "",
+            "nullable": union.nullable,
+            "union": true,
+            "idlType": union
+                .types
+                .iter()
+                .map(|r#type| type_to_json(r#type, context))
+                .collect::<Vec<_>>(),
+        }),
+        Type::Standard(standard) => json!({
+            "type": context,
+            "extAttrs": ext_attrs_to_json(&standard.ext_attrs),
+            "generic": "",
+            "nullable": standard.nullable,
+            "union": false,
+            "idlType": standard.name.to_string(),
+        }),
+    }
+}
+
+fn const_value_to_json(value: &ConstValue) -> Value {
+    match value {
+        ConstValue::Boolean(boolean) => json!({ "type": "boolean", "value": boolean }),
+        ConstValue::Integer(integer) => json!({ "type": "number", "value": integer.to_string() }),
+        ConstValue::Decimal(decimal) => json!({ "type": "number", "value": decimal.to_string() }),
+        ConstValue::NegativeInfinity => json!({ "type": "Infinity", "negative": true }),
+        ConstValue::Infinity => json!({ "type": "Infinity", "negative": false }),
+        ConstValue::NaN => json!({ "type": "NaN" }),
+    }
+}
+
+fn default_value_to_json(value: &DefaultValue) -> Value {
+    match value {
+        DefaultValue::Boolean(boolean) => json!({ "type": "boolean", "value": boolean }),
+        DefaultValue::Integer(integer) => json!({ "type": "number", "value": integer.to_string() }),
+        DefaultValue::Decimal(decimal) => json!({ "type": "number", "value": decimal.to_string() }),
+        DefaultValue::String(string) => json!({ "type": "string", "value": string.as_str() }),
+        DefaultValue::Null => json!({ "type": "null" }),
+        DefaultValue::Infinity => json!({ "type": "Infinity", "negative": false }),
+        DefaultValue::NegativeInfinity => json!({ "type": "Infinity", "negative": true }),
+        DefaultValue::NaN => json!({ "type": "NaN" }),
+        DefaultValue::Undefined => json!({ "type": "undefined" }),
+        DefaultValue::Sequence => json!({ "type": "sequence", "value": [] }),
+        DefaultValue::Dictionary => json!({ "type": "dictionary" }),
+    }
+}
+
+/* Import */
+
+impl<'a> Node<'a> {
+    fn new(json: &'a Value, kind: &'a str) -> Result<Self, Webidl2Error> {
+        let object = json
+            .as_object()
+            .ok_or_else(|| Webidl2Error::new(format!("expected an object for `{}`", kind)))?;
+        let kind = object.get("type").and_then(Value::as_str).unwrap_or(kind);
+
+        Ok(Node { kind, object })
+    }
+
+    fn get(&self, key: &str) -> Result<&'a Value, Webidl2Error> {
+        self.object
+            .get(key)
+            .ok_or_else(|| Webidl2Error::new(format!("`{}` has no `{}`", self.kind, key)))
+    }
+
+    fn str(&self, key: &str) -> Result<&'a str, Webidl2Error> {
+        self.get(key)?
+            .as_str()
+            .ok_or_else(|| self.invalid(key, "a string"))
+    }
+
+    // `None` if the field is `null` or missing.
+    fn opt_str(&self, key: &str) -> Result<Option<&'a str>, Webidl2Error> {
+        match self.object.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => value
+                .as_str()
+                .map(Some)
+                .ok_or_else(|| self.invalid(key, "a string")),
+        }
+    }
+
+    // `false` if the field is missing.
+    fn flag(&self, key: &str) -> Result<bool, Webidl2Error> {
+        match self.object.get(key) {
+            None => Ok(false),
+            Some(value) => value
+                .as_bool()
+                .ok_or_else(|| self.invalid(key, "a boolean")),
+        }
+    }
+
+    fn array(&self, key: &str) -> Result<&'a [Value], Webidl2Error> {
+        self.get(key)?
+            .as_array()
+            .map(Vec::as_slice)
+            .ok_or_else(|| self.invalid(key, "an array"))
+    }
+
+    fn ext_attrs(&self) -> Result<Vec<ExtendedAttribute>, Webidl2Error> {
+        match self.object.get("extAttrs") {
+            None => Ok(vec![]),
+            Some(_) => self
+                .array("extAttrs")?
+                .iter()
+                .map(ext_attr_from_json)
+                .collect(),
+        }
+    }
+
+    fn arguments(&self) -> Result<Vec<Argument>, Webidl2Error> {
+        self.array("arguments")?
+            .iter()
+            .map(argument_from_json)
+            .collect()
+    }
+
+    fn members(&self) -> Result<Vec<Member>, Webidl2Error> {
+        self.array("members")?
+            .iter()
+            .map(member_from_json)
+            .collect()
+    }
+
+    fn idl_type(&self, context: &str) -> Result<Type, Webidl2Error> {
+        type_from_json(self.get("idlType")?, Some(context))
+    }
+
+    fn invalid(&self, key: &str, expected: &str) -> Webidl2Error {
+        Webidl2Error::new(format!(
+            "expected {} for `{}` of `{}`",
+            expected, key, self.kind
+        ))
+    }
+
+    fn unsupported(&self, what: &str) -> Webidl2Error {
+        Webidl2Error::new(format!("{} of `{}` isn't supported", what, self.kind))
+    }
+}
+
+fn definition_from_json(json: &Value) -> Result<Definition, Webidl2Error> {
+    let node = Node::new(json, "definition")?;
+    let identifier = || node.str("name").map(str::to_string);
+
+    Ok(match node.kind {
+        "interface" => Definition::Interface(Interface {
+            ext_attrs: node.ext_attrs()?,
+            partial: node.flag("partial")?,
+            identifier: identifier()?,
+            inheritance: node.opt_str("inheritance")?.map(str::to_string),
+            members: node.members()?,
+            span: None,
+        }),
+        "interface mixin" => Definition::InterfaceMixin(InterfaceMixin {
+            ext_attrs: node.ext_attrs()?,
+            partial: node.flag("partial")?,
+            identifier: identifier()?,
+            members: node.members()?,
+            span: None,
+        }),
+        "includes" => Definition::Includes(Includes {
+            ext_attrs: node.ext_attrs()?,
+            interface: node.str("target")?.to_string(),
+            mixin: node.str("includes")?.to_string(),
+            span: None,
+        }),
+        "callback interface" => Definition::CallbackInterface(CallbackInterface {
+            ext_attrs: node.ext_attrs()?,
+            identifier: identifier()?,
+            members: node.members()?,
+            span: None,
+        }),
+        "namespace" => Definition::Namespace(Namespace {
+            ext_attrs: node.ext_attrs()?,
+            partial: node.flag("partial")?,
+            identifier: identifier()?,
+            members: node.members()?,
+            span: None,
+        }),
+        "dictionary" => Definition::Dictionary(Dictionary {
+            ext_attrs: node.ext_attrs()?,
+            partial: node.flag("partial")?,
+            identifier: identifier()?,
+            inheritance: node.opt_str("inheritance")?.map(str::to_string),
+            members: node
+                .array("members")?
+                .iter()
+                .map(field_from_json)
+                .collect::<Result<_, _>>()?,
+            span: None,
+        }),
+        "enum" => Definition::Enumeration(Enumeration {
+            ext_attrs: node.ext_attrs()?,
+            identifier: identifier()?,
+            values: node
+                .array("values")?
+                .iter()
+                .map(|value| {
+                    let value = Node::new(value, "enum-value")?.str("value")?;
+                    string_literal(value)
+                })
+                .collect::<Result<_, _>>()?,
+            span: None,
+        }),
+        "callback" => Definition::CallbackFunction(CallbackFunction {
+            ext_attrs: node.ext_attrs()?,
+            identifier: identifier()?,
+            r#type: node.idl_type("return-type")?,
+            arguments: node.arguments()?,
+            span: None,
+        }),
+        "typedef" => Definition::Typedef(Typedef {
+            ext_attrs: node.ext_attrs()?,
+            r#type: node.idl_type("typedef-type")?,
+            identifier: identifier()?,
+            span: None,
+        }),
+        kind => {
+            return Err(Webidl2Error::new(format!(
+                "unknown definition type `{}`",
+                kind
+            )))
+        }
+    })
+}
+
+fn member_from_json(json: &Value) -> Result<Member, Webidl2Error> {
+    let node = Node::new(json, "member")?;
+    let identifier = || node.str("name").map(str::to_string);
+    let special = node.opt_str("special")?.unwrap_or_default();
+
+    Ok(match node.kind {
+        "const" => Member::Constant(Constant {
+            ext_attrs: node.ext_attrs()?,
+            r#type: node.idl_type("const-type")?,
+            identifier: identifier()?,
+            value: const_value_from_json(node.get("value")?)?,
+            span: None,
+        }),
+        "attribute" => Member::Attribute(Attribute {
+            ext_attrs: node.ext_attrs()?,
+            readonly: node.flag("readonly")?,
+            special: match special {
+                "" => None,
+                "static" => Some(AttrSpecial::Static),
+                "stringifier" => Some(AttrSpecial::Stringifier),
+                "inherit" => Some(AttrSpecial::Inherit),
+                _ => return Err(node.unsupported(&format!("`{}`", special))),
+            },
+            r#type: node.idl_type("attribute-type")?,
+            identifier: identifier()?,
+            span: None,
+        }),
+        "operation" if special == "stringifier" => {
+            if !node.get("idlType")?.is_null() {
+                return Err(node.unsupported("`stringifier` with a return type"));
+            }
+            Member::Stringifer(Stringifer {
+                ext_attrs: node.ext_attrs()?,
+                span: None,
+            })
+        }
+        "operation" => Member::Operation(Operation {
+            ext_attrs: node.ext_attrs()?,
+            special: match special {
+                "" => None,
+                "static" => Some(OpSpecial::Static),
+                "getter" => Some(OpSpecial::Getter),
+                "setter" => Some(OpSpecial::Setter),
+                "deleter" => Some(OpSpecial::Deleter),
+                _ => return Err(node.unsupported(&format!("`{}`", special))),
+            },
+            r#type: node.idl_type("return-type")?,
+            identifier: identifier()?,
+            arguments: node.arguments()?,
+            span: None,
+        }),
+        "constructor" => Member::Constructor(Constructor {
+            ext_attrs: node.ext_attrs()?,
+            arguments: node.arguments()?,
+            span: None,
+        }),
+        // Older versions of webidl2.js use `async iterable`.
+        "iterable" | "async_iterable" | "async iterable" => {
+            let mut types = types_from_json(&node, 1..=2, None)?;
+            let value_type = types.pop().unwrap();
+            let arguments = node.arguments()?;
+            Member::Iterable(Iterable {
+                ext_attrs: node.ext_attrs()?,
+                r#async: node.kind != "iterable" || node.flag("async")?,
+                key_type: types.pop(),
+                value_type,
+                arguments: (!arguments.is_empty()).then_some(arguments),
+                span: None,
+            })
+        }
+        "maplike" => {
+            let mut types = types_from_json(&node, 2..=2, None)?;
+            let value_type = types.pop().unwrap();
+            Member::Maplike(Maplike {
+                ext_attrs: node.ext_attrs()?,
+                readonly: node.flag("readonly")?,
+                key_type: types.pop().unwrap(),
+                value_type,
+                span: None,
+            })
+        }
+        "setlike" => Member::Setlike(Setlike {
+            ext_attrs: node.ext_attrs()?,
+            readonly: node.flag("readonly")?,
+            r#type: types_from_json(&node, 1..=1, None)?.pop().unwrap(),
+            span: None,
+        }),
+        kind => return Err(Webidl2Error::new(format!("unknown member type `{}`", kind))),
+    })
+}
+
+fn field_from_json(json: &Value) -> Result<DictionaryMember, Webidl2Error> {
+    let node = Node::new(json, "field")?;
+
+    Ok(DictionaryMember {
+        ext_attrs: node.ext_attrs()?,
+        required: node.flag("required")?,
+        r#type: node.idl_type("dictionary-type")?,
+        identifier: node.str("name")?.to_string(),
+        default: default_value_from_json(node.object.get("default"))?,
+        span: None,
+    })
+}
+
+fn argument_from_json(json: &Value) -> Result<Argument, Webidl2Error> {
+    let node = Node::new(json, "argument")?;
+
+    Ok(Argument {
+        ext_attrs: node.ext_attrs()?,
+        optional: node.flag("optional")?,
+        r#type: node.idl_type("argument-type")?,
+        variadic: node.flag("variadic")?,
+        identifier: node.str("name")?.to_string(),
+        default: default_value_from_json(node.object.get("default"))?,
+        span: None,
+    })
+}
+
+fn ext_attr_from_json(json: &Value) -> Result<ExtendedAttribute, Webidl2Error> {
+    let node = Node::new(json, "extended-attribute")?;
+    let arguments = match node.object.get("arguments") {
+        None => vec![],
+        Some(_) => node.arguments()?,
+    };
+
+    let value = match node.object.get("rhs") {
+        None | Some(Value::Null) => {
+            (!arguments.is_empty()).then_some(ExtAttrValue::ArgumentList(arguments))
+        }
+        Some(rhs) => {
+            let rhs = Node::new(rhs, "rhs")?;
+            let list = || -> Result<Vec<&str>, Webidl2Error> {
+                rhs.array("value")?
+                    .iter()
+                    .map(|item| Node::new(item, rhs.kind)?.str("value"))
+                    .collect()
+            };
+            Some(match rhs.kind {
+                "identifier" if !arguments.is_empty() => {
+                    ExtAttrValue::NamedArgumentList(NamedArgumentList {
+                        identifier: rhs.str("value")?.to_string(),
+                        arguments,
+                    })
+                }
+                "identifier" => ExtAttrValue::Identifier(rhs.str("value")?.to_string()),
+                "identifier-list" => {
+                    ExtAttrValue::IdentifierList(list()?.into_iter().map(str::to_string).collect())
+                }
+                "string" => ExtAttrValue::String(quoted_string_literal(rhs.str("value")?)?),
+                "string-list" => ExtAttrValue::StringList(
+                    list()?
+                        .into_iter()
+                        .map(quoted_string_literal)
+                        .collect::<Result<_, _>>()?,
+                ),
+                "*" => ExtAttrValue::Wildcard,
+                kind => return Err(node.unsupported(&format!("value of type `{}`", kind))),
+            })
+        }
+    };
+
+    Ok(ExtendedAttribute {
+        identifier: node.str("name")?.to_string(),
+        value,
+        span: None,
+    })
+}
+
+// `context` is the `type` the type must have, see `type_to_json`.
+fn type_from_json(json: &Value, context: Option<&str>) -> Result<Type, Webidl2Error> {
+    let node = Node::new(json, "idlType")?;
+    if node.opt_str("type")? != context {
+        let expected = context.map_or("`null`".to_string(), |context| format!("`{}`", context));
+        return Err(node.invalid("type", &expected));
+    }
+    let ext_attrs = node.ext_attrs()?;
+    let nullable = node.flag("nullable")?;
+    let generic = node.opt_str("generic")?.unwrap_or_default();
+
+    if node.flag("union")? {
+        let types = node
+            .array("idlType")?
+            .iter()
+            .map(|r#type| type_from_json(r#type, context))
+            .collect::<Result<_, _>>()?;
+        return Ok(Type::Union(UnionType {
+            ext_attrs,
+            types,
+            nullable,
+            span: None,
+        }));
+    }
+    if generic.is_empty() {
+        return Ok(Type::Standard(StandardType {
+            ext_attrs,
+            name: standard_type_name(node.str("idlType")?),
+            nullable,
+            span: None,
+        }));
+    }
+
+    // Only union and standard types can have extended attributes here.
+    if !ext_attrs.is_empty() {
+        return Err(node.unsupported(&format!("extended attributes on `{}`", generic)));
+    }
+    let mut types = types_from_json(&node, 1..=2, context)?
+        .into_iter()
+        .map(Box::new);
+    let r#type = types.next().unwrap();
+    Ok(match (generic, types.next()) {
+        ("sequence", None) => Type::Sequence(SequenceType {
+            r#type,
+            nullable,
+            span: None,
+        }),
+        ("Promise", None) => Type::Promise(PromiseType {
+            r#type,
+            nullable,
+            span: None,
+        }),
+        ("FrozenArray", None) => Type::FrozenArray(FrozenArrayType {
+            r#type,
+            nullable,
+            span: None,
+        }),
+        ("ObservableArray", None) => Type::ObservableArray(ObservableArrayType {
+            r#type,
+            nullable,
+            span: None,
+        }),
+        ("record", Some(value)) if !nullable => {
+            let key = match *r#type {
+                Type::Standard(StandardType {
+                    name: StandardTypeName::Primitive(PrimitiveType::DOMString),
+                    ..
+                }) => RecordTypeKey::DOMString,
+                Type::Standard(StandardType {
+                    name: StandardTypeName::Primitive(PrimitiveType::USVString),
+                    ..
+                }) => RecordTypeKey::USVString,
+                Type::Standard(StandardType {
+                    name: StandardTypeName::Primitive(PrimitiveType::ByteString),
+                    ..
+                }) => RecordTypeKey::ByteString,
+                _ => return Err(node.unsupported("key type")),
+            };
+            Type::Record(RecordType {
+                key,
+                value,
+                span: None,
+            })
+        }
+        _ => return Err(node.unsupported(&format!("generic `{}` with these types", generic))),
+    })
+}
+
+// The types of a generic type or of an iterable, maplike or setlike declaration.
+fn types_from_json(
+    node: &Node,
+    count: std::ops::RangeInclusive<usize>,
+    context: Option<&str>,
+) -> Result<Vec<Type>, Webidl2Error> {
+    let types = node.array("idlType")?;
+    if !count.contains(&types.len()) {
+        return Err(node.invalid("idlType", "a different number of types"));
+    }
+
+    types
+        .iter()
+        .map(|r#type| type_from_json(r#type, context))
+        .collect()
+}
+
+fn const_value_from_json(json: &Value) -> Result<ConstValue, Webidl2Error> {
+    match default_value_from_json(Some(json))? {
+        Some(DefaultValue::Boolean(boolean)) => Ok(ConstValue::Boolean(boolean)),
+        Some(DefaultValue::Integer(integer)) => Ok(ConstValue::Integer(integer)),
+        Some(DefaultValue::Decimal(decimal)) => Ok(ConstValue::Decimal(decimal)),
+        Some(DefaultValue::Infinity) => Ok(ConstValue::Infinity),
+        Some(DefaultValue::NegativeInfinity) => Ok(ConstValue::NegativeInfinity),
+        Some(DefaultValue::NaN) => Ok(ConstValue::NaN),
+        _ => Err(Webidl2Error::new(format!(
+            "invalid value of `const`: {}",
+            json
+        ))),
+    }
+}
+
+// `None` if there is no default value.
+fn default_value_from_json(json: Option<&Value>) -> Result<Option<DefaultValue>, Webidl2Error> {
+    let Some(json) = json.filter(|json| !json.is_null()) else {
+        return Ok(None);
+    };
+    let node = Node::new(json, "default")?;
+
+    Ok(Some(match node.kind {
+        "boolean" => DefaultValue::Boolean(node.flag("value")?),
+        "number" => {
+            let value = node.str("value")?;
+            if let Some(integer) = parse_all(value, parser::parse_integer) {
+                DefaultValue::Integer(integer)
+            } else if let Some(decimal) = parse_all(value, parser::parse_decimal) {
+                DefaultValue::Decimal(decimal)
+            } else {
+                return Err(node.invalid("value", "a number"));
+            }
+        }
+        "string" => DefaultValue::String(string_literal(node.str("value")?)?),
+        "null" => DefaultValue::Null,
+        "Infinity" if node.flag("negative")? => DefaultValue::NegativeInfinity,
+        "Infinity" => DefaultValue::Infinity,
+        "NaN" => DefaultValue::NaN,
+        "undefined" => DefaultValue::Undefined,
+        "sequence" => DefaultValue::Sequence,
+        "dictionary" => DefaultValue::Dictionary,
+        kind => {
+            return Err(Webidl2Error::new(format!(
+                "unknown default value type `{}`",
+                kind
+            )))
+        }
+    }))
+}
+
+fn standard_type_name(name: &str) -> StandardTypeName {
+    match parse_all(name, StandardTypeName::parse) {
+        Some(StandardTypeName::Primitive(primitive)) => StandardTypeName::Primitive(primitive),
+        _ => StandardTypeName::Identifier(name.to_string()),
+    }
+}
+
+fn string_literal(value: &str) -> Result<StringLiteral, Webidl2Error> {
+    StringLiteral::new(value)
+        .ok_or_else(|| Webidl2Error::new(format!("string contains `\"`: {}", value)))
+}
+
+// Strings of extended attributes are given with their quotes.
+fn quoted_string_literal(value: &str) -> Result<StringLiteral, Webidl2Error> {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .ok_or_else(|| Webidl2Error::new(format!("string isn't quoted: {}", value)))
+        .and_then(string_literal)
+}
+
+// Runs one of the parsers on the whole of `input`.
+fn parse_all<O>(input: &str, mut f: impl FnMut(parser::Input) -> parser::IResult<O>) -> Option<O> {
+    let state = parser::State::default();
+    match f(parser::Input::new_extra(input, &state)) {
+        Ok((rest, o)) if rest.is_empty() => Some(o),
+        _ => None,
+    }
+}
+
+/* Trait implementations */
+
+impl fmt::Display for Webidl2Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid webidl2.js JSON: {}", self.message)
+    }
+}
+
+impl std::error::Error for Webidl2Error {}